@@ -0,0 +1,199 @@
+use std::{collections::BTreeMap, fs, path::{Path, PathBuf}, process::ExitCode, time::Duration};
+
+use aoc_common::{workspace_root, AllocationStats, AocError, Part};
+use serde::{Deserialize, Serialize};
+
+use crate::days::{self, Day};
+
+/// Fastest run time of each phase of a day, in nanoseconds.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct PhaseTimings {
+    pub parse_ns: u64,
+    pub part1_ns: u64,
+    pub part2_ns: u64,
+    /// Only recorded by a runner built with the `count-allocations` feature.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allocations: Option<PhaseAllocations>,
+}
+
+/// Allocations of each phase of a day, which are the same in every run.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct PhaseAllocations {
+    pub parse: AllocationStats,
+    pub part1: AllocationStats,
+    pub part2: AllocationStats,
+}
+
+impl PhaseTimings {
+    fn phases(&self) -> [(&'static str, u64); 3] {
+        [("parse", self.parse_ns), ("part1", self.part1_ns), ("part2", self.part2_ns)]
+    }
+}
+
+/// Saved timings keyed by day, e.g. "day_05".
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Baseline {
+    pub days: BTreeMap<String, PhaseTimings>,
+}
+
+impl Baseline {
+    pub fn default_path() -> PathBuf {
+        // timings are machine specific, so they live next to criterion's own baselines
+        workspace_root().join("target").join("aoc-bench").join("baseline.json")
+    }
+
+    pub fn load(path: &Path) -> Result<Option<Self>, String> {
+        if !path.exists() {
+            return Ok(None);
+        }
+        let contents = fs::read_to_string(path).map_err(|error| format!("Failed to read {} - error: {}", path.display(), error))?;
+        serde_json::from_str(&contents)
+            .map(Some)
+            .map_err(|error| format!("Invalid baseline {} - error: {}", path.display(), error))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|error| format!("Failed to create {} - error: {}", parent.display(), error))?;
+        }
+        let contents = serde_json::to_string_pretty(self).map_err(|error| error.to_string())?;
+        fs::write(path, contents).map_err(|error| format!("Failed to write {} - error: {}", path.display(), error))
+    }
+}
+
+pub struct BenchOptions {
+    pub day: Option<u8>,
+    pub runs: usize,
+    pub baseline: Option<PathBuf>,
+    pub save_baseline: bool,
+    pub threshold: f64,
+}
+
+/// Time every phase of a day, keeping the fastest of `runs` repetitions to filter out noise.
+fn time_day(day: &Day, runs: usize) -> Result<PhaseTimings, AocError> {
+    let input_source = day.default_input();
+    let input_string = input_source.read()?;
+    let mut fastest = [Duration::MAX; 3];
+    let mut allocations = None;
+    for _ in 0..runs.max(1) {
+        let day_run = day.run(&input_string, &Part::ALL).map_err(|error| error.with_input_file(input_source.display_path()))?;
+        fastest[0] = fastest[0].min(day_run.parse_elapsed);
+        for part_run in &day_run.parts {
+            let index = part_run.part.number() as usize;
+            fastest[index] = fastest[index].min(part_run.elapsed);
+        }
+        allocations = day_run.parse_allocations.and_then(|parse| {
+            let part = |part| day_run.part(part).and_then(|part_run| part_run.allocations);
+            Some(PhaseAllocations { parse, part1: part(Part::One)?, part2: part(Part::Two)? })
+        });
+    }
+    Ok(PhaseTimings {
+        parse_ns: fastest[0].as_nanos() as u64,
+        part1_ns: fastest[1].as_nanos() as u64,
+        part2_ns: fastest[2].as_nanos() as u64,
+        allocations,
+    })
+}
+
+pub fn format_duration(nanos: u64) -> String {
+    match nanos {
+        n if n < 1_000 => format!("{} ns", n),
+        n if n < 1_000_000 => format!("{:.1} µs", n as f64 / 1e3),
+        n if n < 1_000_000_000 => format!("{:.1} ms", n as f64 / 1e6),
+        n => format!("{:.2} s", n as f64 / 1e9),
+    }
+}
+
+pub fn format_bytes(bytes: u64) -> String {
+    match bytes {
+        b if b < 1 << 10 => format!("{} B", b),
+        b if b < 1 << 20 => format!("{:.1} KiB", b as f64 / 1024.0),
+        b if b < 1 << 30 => format!("{:.1} MiB", b as f64 / (1u64 << 20) as f64),
+        b => format!("{:.2} GiB", b as f64 / (1u64 << 30) as f64),
+    }
+}
+
+pub fn format_allocations(stats: &AllocationStats) -> String {
+    format!("{} allocations, {} allocated, {} peak", stats.allocations, format_bytes(stats.bytes_allocated), format_bytes(stats.peak_bytes))
+}
+
+/// Percentage by which `current` is slower than `previous`, negative when it got faster.
+fn slowdown_percent(previous: u64, current: u64) -> f64 {
+    (current as f64 - previous as f64) / (previous.max(1) as f64) * 100.0
+}
+
+pub fn bench(options: BenchOptions) -> ExitCode {
+    let baseline_path = options.baseline.unwrap_or_else(Baseline::default_path);
+    let previous = match Baseline::load(&baseline_path) {
+        Ok(previous) => previous,
+        Err(error) => {
+            eprintln!("{}", error);
+            return ExitCode::FAILURE;
+        }
+    };
+    let solutions = days::all().into_iter().filter(|solution| options.day.is_none_or(|day| solution.day == day)).collect::<Vec<_>>();
+    if solutions.is_empty() {
+        eprintln!("Day {} is not implemented", options.day.unwrap_or_default());
+        return ExitCode::FAILURE;
+    }
+
+    println!("{:<6} {:>12} {:>12} {:>12}", "Day", "Parse", "Part 1", "Part 2");
+    let mut current = previous.as_ref().map(|baseline| baseline.days.clone()).unwrap_or_default();
+    let mut regressions = vec![];
+    let mut allocation_rows = vec![];
+    for solution in solutions {
+        let key = format!("day_{:02}", solution.day);
+        let timings = match time_day(&solution, options.runs) {
+            Ok(timings) => timings,
+            Err(error) => {
+                eprintln!("Day {}: {}", solution.day, error);
+                return ExitCode::FAILURE;
+            }
+        };
+        let cells = timings.phases().map(|(phase, nanos)| {
+            let previous_nanos = previous.as_ref()
+                .and_then(|baseline| baseline.days.get(&key))
+                .and_then(|previous_timings| previous_timings.phases().into_iter().find(|&(p, _)| p == phase))
+                .map(|(_, previous_nanos)| previous_nanos);
+            match previous_nanos {
+                Some(previous_nanos) if slowdown_percent(previous_nanos, nanos) > options.threshold => {
+                    let percent = slowdown_percent(previous_nanos, nanos);
+                    regressions.push(format!("Day {} {}: {} -> {} (+{:.0}%)", solution.day, phase, format_duration(previous_nanos), format_duration(nanos), percent));
+                    format!("{} !", format_duration(nanos))
+                }
+                _ => format_duration(nanos),
+            }
+        });
+        println!("{:<6} {:>12} {:>12} {:>12}", solution.day, cells[0], cells[1], cells[2]);
+        if let Some(allocations) = &timings.allocations {
+            let cells = [allocations.parse, allocations.part1, allocations.part2]
+                .map(|stats| format!("{} / {}", stats.allocations, format_bytes(stats.peak_bytes)));
+            allocation_rows.push(format!("{:<6} {:>20} {:>20} {:>20}", solution.day, cells[0], cells[1], cells[2]));
+        }
+        current.insert(key, timings);
+    }
+
+    if !allocation_rows.is_empty() {
+        println!("\nAllocations / peak heap usage");
+        println!("{:<6} {:>20} {:>20} {:>20}", "Day", "Parse", "Part 1", "Part 2");
+        for row in &allocation_rows {
+            println!("{}", row);
+        }
+    }
+
+    if options.save_baseline {
+        if let Err(error) = (Baseline { days: current }).save(&baseline_path) {
+            eprintln!("{}", error);
+            return ExitCode::FAILURE;
+        }
+        println!("Saved baseline to {}", baseline_path.display());
+    }
+    if !regressions.is_empty() {
+        println!("Slower than baseline by more than {}%:", options.threshold);
+        for regression in &regressions {
+            println!("  {}", regression);
+        }
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
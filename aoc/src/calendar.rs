@@ -0,0 +1,183 @@
+//! The 25 days of the year as a grid, with the stars, verification status and run time of each day.
+
+use std::{env, io::{self, IsTerminal}, process::ExitCode};
+
+use aoc_common::{ExpectedAnswers, Part, Verdict};
+use rayon::prelude::*;
+
+use crate::{bench::format_duration, config::Config, days, run_all};
+
+const DAYS_PER_ROW: u8 = 5;
+/// Width of a cell between its borders.
+const CELL_WIDTH: usize = 16;
+
+const DIM: &str = "\x1b[2m";
+const GREEN: &str = "\x1b[32m";
+const RED: &str = "\x1b[31m";
+const RESET: &str = "\x1b[0m";
+
+/// What the calendar shows for one day.
+pub struct DayCell {
+    pub day: u8,
+    /// Parts with an expected answer on record.
+    pub stars: usize,
+    /// None for days without a solution.
+    pub solved: Option<Solved>,
+}
+
+pub struct Solved {
+    pub status: Status,
+    pub elapsed_ns: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// Every part matches its expected answer.
+    Pass,
+    /// A part failed, panicked or gave a different answer than expected.
+    Fail,
+    /// Nothing failed, but some answers are not on record.
+    Unknown,
+}
+
+impl Status {
+    fn label(self) -> &'static str {
+        match self {
+            Status::Pass => "PASS",
+            Status::Fail => "FAIL",
+            Status::Unknown => "UNKNOWN",
+        }
+    }
+}
+
+/// The calendar as text, with ANSI colors when `color` is set.
+pub fn render(year: u16, cells: &[DayCell], color: bool) -> String {
+    let paint = |style: &str, text: String| if color { format!("{}{}{}", style, text, RESET) } else { text };
+    let border = format!("+{}\n", format!("{}+", "-".repeat(CELL_WIDTH)).repeat(DAYS_PER_ROW as usize));
+    let mut calendar = format!("Advent of Code {}\n{}", year, border);
+    for row in cells.chunks(DAYS_PER_ROW as usize) {
+        let mut lines = [String::from("|"), String::from("|")];
+        for cell in row {
+            let stars = format!("{:<2}", "*".repeat(cell.stars));
+            let (first, second) = match &cell.solved {
+                Some(solved) => {
+                    let status = format!("{:>8}", solved.status.label());
+                    let status = match solved.status {
+                        Status::Pass => paint(GREEN, status),
+                        Status::Fail => paint(RED, status),
+                        Status::Unknown => status,
+                    };
+                    (format!(" {:>2} {} {} ", cell.day, stars, status), format!(" {:>14} ", format_duration(solved.elapsed_ns)))
+                }
+                None => (
+                    paint(DIM, format!(" {:>2} {} {:>8} ", cell.day, stars, "")),
+                    paint(DIM, format!(" {:>14} ", "-")),
+                ),
+            };
+            lines[0].push_str(&format!("{}|", first));
+            lines[1].push_str(&format!("{}|", second));
+        }
+        for line in lines {
+            calendar.push_str(&line);
+            calendar.push('\n');
+        }
+        calendar.push_str(&border);
+    }
+    calendar
+}
+
+/// Solve every implemented day on the rayon thread pool and draw the calendar.
+pub fn calendar() -> ExitCode {
+    let year = match Config::load(&Config::default_path()) {
+        Ok(config) => config.year,
+        Err(error) => {
+            eprintln!("{}", error);
+            return ExitCode::FAILURE;
+        }
+    };
+    let expected_answers = ExpectedAnswers::load(ExpectedAnswers::default_path()).unwrap_or_else(|error| {
+        eprintln!("{}", error);
+        ExpectedAnswers::default()
+    });
+    let solutions = days::all();
+    let outcomes = solutions.par_iter().map(|solution| run_all::run_day(solution, &Part::ALL)).collect::<Vec<_>>();
+
+    let cells = (1..=25)
+        .map(|day| {
+            let stars = Part::ALL.into_iter().filter(|&part| expected_answers.get(day, part, "input.txt").is_some()).count();
+            let solved = outcomes.iter().find(|outcome| outcome.day == day).map(|outcome| {
+                let mut status = Status::Pass;
+                let mut elapsed_ns = 0;
+                for (part, result) in &outcome.parts {
+                    let verdict = match result {
+                        Ok(day_run) => {
+                            let part_run = &day_run.parts[0];
+                            elapsed_ns += part_run.elapsed.as_nanos() as u64;
+                            expected_answers.verify(day, *part, "input.txt", &part_run.answer)
+                        }
+                        Err(_) => Verdict::Fail { expected: String::new() },
+                    };
+                    status = match (status, verdict) {
+                        (Status::Fail, _) | (_, Verdict::Fail { .. }) => Status::Fail,
+                        (Status::Unknown, _) | (_, Verdict::Unknown) => Status::Unknown,
+                        (Status::Pass, Verdict::Pass) => Status::Pass,
+                    };
+                }
+                Solved { status, elapsed_ns }
+            });
+            DayCell { day, stars, solved }
+        })
+        .collect::<Vec<_>>();
+
+    let color = io::stdout().is_terminal() && env::var_os("NO_COLOR").is_none();
+    print!("{}", render(year, &cells, color));
+    let solved = cells.iter().filter_map(|cell| cell.solved.as_ref()).collect::<Vec<_>>();
+    println!(
+        "{} stars, {} of {} days verify, {} total",
+        cells.iter().map(|cell| cell.stars).sum::<usize>(),
+        solved.iter().filter(|solved| solved.status == Status::Pass).count(),
+        solved.len(),
+        format_duration(solved.iter().map(|solved| solved.elapsed_ns).sum()),
+    );
+    if solved.iter().any(|solved| solved.status == Status::Fail) {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cells() -> Vec<DayCell> {
+        (1..=25)
+            .map(|day| DayCell {
+                day,
+                stars: if day < 3 { 2 } else { 0 },
+                solved: (day < 3).then_some(Solved { status: if day == 1 { Status::Pass } else { Status::Fail }, elapsed_ns: 1_500_000 }),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn draws_five_rows_of_five_days() {
+        let calendar = render(2023, &cells(), false);
+        let lines = calendar.lines().collect::<Vec<_>>();
+        assert_eq!(lines[0], "Advent of Code 2023");
+        // a border above every row and below the last one, with two lines per row
+        assert_eq!(lines.len(), 1 + 6 + 5 * 2);
+        assert!(lines.iter().skip(1).all(|line| line.len() == 1 + 5 * (CELL_WIDTH + 1)));
+        assert_eq!(&lines[2][..CELL_WIDTH + 2], "|  1 **     PASS |");
+        assert!(lines[2].contains("|  2 **     FAIL |"));
+        assert!(lines[2].ends_with(&format!("|  5{}|", " ".repeat(CELL_WIDTH - 3))));
+    }
+
+    #[test]
+    fn dims_unimplemented_days() {
+        let calendar = render(2023, &cells(), true);
+        assert!(calendar.contains(&format!("|  1 ** {}    PASS{} |", GREEN, RESET)));
+        assert!(calendar.contains(&format!("{} 25", DIM)));
+        assert!(!calendar.contains(&format!("{}  1", DIM)));
+    }
+}
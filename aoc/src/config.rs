@@ -0,0 +1,61 @@
+use std::{env, fs, path::{Path, PathBuf}};
+
+use aoc_common::workspace_root;
+use serde::Deserialize;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const DEFAULT_YEAR: u16 = 2023;
+
+/// Settings for talking to the Advent of Code website, loaded from `aoc.toml` in the workspace root.
+/// `AOC_SESSION` and `AOC_BASE_URL` override the file, so the session token never has to be written to disk.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub session: Option<String>,
+    pub base_url: String,
+    pub year: u16,
+    /// Relative paths are resolved against the workspace root.
+    pub cache_dir: PathBuf,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            session: None,
+            base_url: DEFAULT_BASE_URL.to_string(),
+            year: DEFAULT_YEAR,
+            cache_dir: PathBuf::from(".aoc-cache"),
+        }
+    }
+}
+
+impl Config {
+    pub fn default_path() -> PathBuf {
+        workspace_root().join("aoc.toml")
+    }
+
+    /// Load the config file if there is one, then apply the environment overrides.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let mut config = if path.exists() {
+            let contents = fs::read_to_string(path).map_err(|error| format!("Failed to read {} - error: {}", path.display(), error))?;
+            toml::from_str::<Config>(&contents).map_err(|error| format!("Invalid config {} - error: {}", path.display(), error))?
+        } else {
+            Config::default()
+        };
+        if let Ok(session) = env::var("AOC_SESSION") {
+            config.session = Some(session);
+        }
+        if let Ok(base_url) = env::var("AOC_BASE_URL") {
+            config.base_url = base_url;
+        }
+        config.cache_dir = workspace_root().join(&config.cache_dir);
+        Ok(config)
+    }
+
+    pub fn session(&self) -> Result<&str, String> {
+        match self.session.as_deref().map(str::trim) {
+            Some(session) if !session.is_empty() => Ok(session),
+            _ => Err(format!("No session token, set AOC_SESSION or `session` in {}", Config::default_path().display())),
+        }
+    }
+}
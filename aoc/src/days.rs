@@ -0,0 +1,66 @@
+use aoc_common::{run, solve, AocError, Answer, DayRun, InputSource, Part, Solution};
+
+/// A registered day, with its solution erased behind function pointers so days can be dispatched at runtime.
+pub struct Day {
+    pub day: u8,
+    embedded_input: Option<&'static str>,
+    solve: fn(&str, Part) -> Result<Answer, AocError>,
+    run: fn(&str, &[Part]) -> Result<DayRun, AocError>,
+}
+
+impl Day {
+    fn of<S: Solution>() -> Self {
+        Self { day: S::DAY, embedded_input: S::EMBEDDED_INPUT, solve: solve::<S>, run: run::<S> }
+    }
+
+    pub fn solve(&self, input_string: &str, part: Part) -> Result<Answer, AocError> {
+        (self.solve)(input_string, part)
+    }
+
+    /// Solve the given parts, timing the parse and part phases separately.
+    pub fn run(&self, input_string: &str, parts: &[Part]) -> Result<DayRun, AocError> {
+        (self.run)(input_string, parts)
+    }
+
+    /// The embedded input when built with the `embedded-input` feature, the day's input.txt otherwise.
+    pub fn default_input(&self) -> InputSource {
+        InputSource::default_for_day(self.day, self.embedded_input)
+    }
+}
+
+pub fn all() -> Vec<Day> {
+    vec![
+        Day::of::<day_01::Day01>(),
+        Day::of::<day_02::Day02>(),
+        Day::of::<day_03::Day03>(),
+        Day::of::<day_04::Day04>(),
+        Day::of::<day_05::Day05>(),
+        Day::of::<day_06::Day06>(),
+        Day::of::<day_07::Day07>(),
+        Day::of::<day_08::Day08>(),
+        Day::of::<day_09::Day09>(),
+        Day::of::<day_10::Day10>(),
+        Day::of::<day_11::Day11>(),
+    ]
+}
+
+pub fn find(day: u8) -> Option<Day> {
+    all().into_iter().find(|d| d.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn line_without_digit_is_a_diagnostic_not_a_panic() {
+        let day = find(1).unwrap();
+        let error = day.run("a1b\nno digit\n", &Part::ALL).unwrap_err();
+        assert!(matches!(&error, AocError::Parse(error) if error.line == 2 && error.expected == "a digit"), "{}", error);
+
+        // spelled-out digits only count in part 2, so part 1 reports the line while part 2 solves
+        assert_eq!(day.solve("xoney\n", Part::Two).unwrap(), Answer::from(11));
+        let error = day.solve("xoney\n", Part::One).unwrap_err();
+        assert!(error.to_string().contains("1 | xoney"), "{}", error);
+    }
+}
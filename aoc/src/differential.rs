@@ -0,0 +1,162 @@
+//! Differential testing: parts with more than one implementation are solved by all of them on
+//! generated inputs, and the first input they disagree on is reported.
+
+use std::{fs, panic, path::{Path, PathBuf}};
+
+use aoc_common::{workspace_root, AocError, Answer, Part};
+
+use crate::{days, generate};
+
+/// One way of solving a part, from the raw input.
+pub struct Implementation {
+    pub name: &'static str,
+    pub solve: fn(&str) -> Result<Answer, AocError>,
+}
+
+/// Other implementations of a part that have to agree with the day's solution, with the input
+/// size they are compared on by default.
+pub struct Candidates {
+    pub day: u8,
+    pub part: Part,
+    pub size: usize,
+    pub alternatives: Vec<Implementation>,
+}
+
+/// A generated input the implementations of a part did not agree on.
+pub struct Disagreement {
+    pub seed: u64,
+    pub input: String,
+    /// Answer or error of the solution and then of every alternative, in the order they are registered.
+    pub answers: Vec<(&'static str, Result<Answer, String>)>,
+}
+
+impl Disagreement {
+    /// Where the input is kept for reproducing the disagreement.
+    pub fn default_path(day: u8, seed: u64) -> PathBuf {
+        workspace_root().join("target").join("aoc-diff").join(format!("day_{:02}_seed_{}.txt", day, seed))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        if let Some(directory) = path.parent() {
+            fs::create_dir_all(directory).map_err(|error| format!("Failed to create {} - error: {}", directory.display(), error))?;
+        }
+        fs::write(path, &self.input).map_err(|error| format!("Failed to write {} - error: {}", path.display(), error))
+    }
+}
+
+pub fn all() -> Vec<Candidates> {
+    vec![
+        Candidates {
+            day: 5,
+            part: Part::Two,
+            size: 1,
+            alternatives: vec![
+                Implementation { name: "by ranges", solve: |input| Ok(day_05::part2_by_ranges(&day_05::parse_almanac(input)?).into()) },
+            ],
+        },
+        Candidates {
+            day: 6,
+            part: Part::One,
+            size: 4,
+            alternatives: vec![
+                Implementation { name: "by bisection", solve: |input| Ok(day_06::part1_by_bisection(&day_06::parse_races(input)?).into()) },
+            ],
+        },
+        Candidates {
+            day: 6,
+            part: Part::Two,
+            size: 3,
+            alternatives: vec![
+                Implementation { name: "by bisection", solve: |input| Ok(day_06::part2_by_bisection(&day_06::parse_races(input)?).into()) },
+            ],
+        },
+        Candidates {
+            day: 11,
+            part: Part::One,
+            size: 40,
+            alternatives: vec![
+                Implementation { name: "by axis", solve: |input| Ok(day_11::sum_of_distances_by_axis(&day_11::parse(input)?, 2).into()) },
+            ],
+        },
+        Candidates {
+            day: 11,
+            part: Part::Two,
+            size: 40,
+            alternatives: vec![
+                Implementation { name: "by axis", solve: |input| Ok(day_11::sum_of_distances_by_axis(&day_11::parse(input)?, 1_000_000).into()) },
+            ],
+        },
+    ]
+}
+
+impl Candidates {
+    /// Solve the inputs generated from each of `seeds` with every implementation and stop at the
+    /// first one they disagree on. Errors and panics count as answers, so they disagree with any number.
+    pub fn compare(&self, seeds: impl IntoIterator<Item = u64>, size: usize) -> Result<Option<Disagreement>, String> {
+        let solution = days::find(self.day).ok_or_else(|| format!("Day {} is not implemented", self.day))?;
+        for seed in seeds {
+            let input = generate::generate(self.day, seed, size)?.input;
+            let mut answers = vec![("solution", solve(|| solution.solve(&input, self.part)))];
+            for alternative in &self.alternatives {
+                answers.push((alternative.name, solve(|| (alternative.solve)(&input))));
+            }
+            let (_, first) = &answers[0];
+            if answers.iter().any(|(_, answer)| answer.is_err() || answer != first) {
+                return Ok(Some(Disagreement { seed, input, answers }));
+            }
+        }
+        Ok(None)
+    }
+}
+
+fn solve(solve: impl FnOnce() -> Result<Answer, AocError> + panic::UnwindSafe) -> Result<Answer, String> {
+    match panic::catch_unwind(solve) {
+        Ok(Ok(answer)) => Ok(answer),
+        Ok(Err(error)) => Err(error.to_string()),
+        Err(_) => Err("panicked".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registered_implementations_agree() {
+        for candidates in all() {
+            let disagreement = candidates.compare(0..5, candidates.size).unwrap();
+            assert!(disagreement.is_none(), "day {} part {} disagrees", candidates.day, candidates.part);
+        }
+    }
+
+    #[test]
+    fn registered_inputs_have_different_answers() {
+        // inputs that all share an answer, like a lowest location of 0, barely exercise the implementations
+        for candidates in all() {
+            let solution = days::find(candidates.day).unwrap();
+            let answers = (0..5)
+                .map(|seed| solution.solve(&generate::generate(candidates.day, seed, candidates.size).unwrap().input, candidates.part).unwrap())
+                .collect::<Vec<_>>();
+            let distinct = answers.iter().enumerate().filter(|(index, answer)| !answers[..*index].contains(answer)).count();
+            assert!(distinct >= 4, "day {} part {} answers {:?}", candidates.day, candidates.part, answers);
+        }
+    }
+
+    #[test]
+    fn reports_the_first_disagreeing_seed() {
+        let candidates = Candidates {
+            day: 6,
+            part: Part::One,
+            size: 1,
+            alternatives: vec![
+                // as if every record was 0
+                Implementation { name: "wrong", solve: |input| Ok(day_06::parse_races(input)?.iter().map(|&(time, _)| time - 1).product::<usize>().into()) },
+            ],
+        };
+        let disagreement = candidates.compare(0..50, 1).unwrap().unwrap();
+        let (_, expected) = &disagreement.answers[0];
+        assert_ne!(&disagreement.answers[1].1, expected);
+        let earlier = candidates.compare(0..disagreement.seed, 1).unwrap();
+        assert!(earlier.is_none());
+    }
+}
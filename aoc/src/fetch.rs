@@ -0,0 +1,120 @@
+use std::{fs, path::PathBuf};
+
+use aoc_common::{input_file_path, normalize_input};
+
+use crate::config::Config;
+
+pub const USER_AGENT: &str = concat!("aoc-2023-runner/", env!("CARGO_PKG_VERSION"));
+
+/// Where a day's downloaded input is cached, e.g. `.aoc-cache/2023/day_05.txt`.
+pub fn cached_input_path(config: &Config, day: u8) -> PathBuf {
+    config.cache_dir.join(config.year.to_string()).join(format!("day_{:02}.txt", day))
+}
+
+/// A day's input from the cache, downloading it only if it has never been fetched before.
+pub fn fetch_input(config: &Config, day: u8) -> Result<String, String> {
+    let cache_path = cached_input_path(config, day);
+    if cache_path.exists() {
+        return fs::read_to_string(&cache_path).map_err(|error| format!("Failed to read {} - error: {}", cache_path.display(), error));
+    }
+
+    let session = config.session()?;
+    let url = format!("{}/{}/day/{}/input", config.base_url.trim_end_matches('/'), config.year, day);
+    let response = ureq::get(&url)
+        .set("Cookie", &format!("session={}", session))
+        .set("User-Agent", USER_AGENT)
+        .call()
+        .map_err(|error| request_error("download", &url, error))?;
+    let contents = response.into_string().map_err(|error| format!("Failed to download {} - error: {}", url, error))?;
+
+    if let Some(parent) = cache_path.parent() {
+        fs::create_dir_all(parent).map_err(|error| format!("Failed to create {} - error: {}", parent.display(), error))?;
+    }
+    fs::write(&cache_path, &contents).map_err(|error| format!("Failed to write {} - error: {}", cache_path.display(), error))?;
+    Ok(contents)
+}
+
+pub fn request_error(action: &str, url: &str, error: ureq::Error) -> String {
+    match error {
+        ureq::Error::Status(status, _) => format!("Failed to {} {} - status {}", action, url, status),
+        // a transport error already names the url
+        ureq::Error::Transport(transport) => format!("Failed to {} - error: {}", action, transport),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Installed {
+    Written(PathBuf),
+    Unchanged(PathBuf),
+}
+
+/// Copy a downloaded input to the day's `src/input.txt`, where the solutions load it from.
+/// An existing input with different contents is only replaced when `force` is set.
+pub fn install_input(day: u8, contents: &str, force: bool) -> Result<Installed, String> {
+    let path = input_file_path(day, "input.txt");
+    if let Ok(existing) = fs::read_to_string(&path) {
+        if normalize_input(&existing) == normalize_input(contents) {
+            return Ok(Installed::Unchanged(path));
+        }
+        // an empty input is the placeholder `aoc new` leaves behind
+        if !force && !existing.trim().is_empty() {
+            return Err(format!("{} already exists with different contents, use --force to replace it", path.display()));
+        }
+    }
+    if !path.parent().is_some_and(|source_dir| source_dir.is_dir()) {
+        return Err(format!("Day {} has no crate to hold {}", day, path.display()));
+    }
+    fs::write(&path, contents).map_err(|error| format!("Failed to write {} - error: {}", path.display(), error))?;
+    Ok(Installed::Written(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::process;
+
+    use super::*;
+    use crate::mock_server::{mock_server, MockRequest};
+
+    fn test_config(name: &str, base_url: String) -> Config {
+        let cache_dir = std::env::temp_dir().join(format!("aoc-fetch-{}-{}", name, process::id()));
+        let _ = fs::remove_dir_all(&cache_dir);
+        Config { session: Some("secret".to_string()), base_url, year: 2023, cache_dir }
+    }
+
+    #[test]
+    fn downloads_once_then_reads_the_cache() {
+        let (base_url, requests) = mock_server(200, "1 2 3\n");
+        let config = test_config("cache", base_url);
+
+        assert_eq!(fetch_input(&config, 5).unwrap(), "1 2 3\n");
+        assert_eq!(fetch_input(&config, 5).unwrap(), "1 2 3\n");
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(*requests, vec![MockRequest {
+            request_line: "GET /2023/day/5/input HTTP/1.1".to_string(),
+            cookie: "session=secret".to_string(),
+            user_agent: USER_AGENT.to_string(),
+            body: String::new(),
+        }]);
+        assert_eq!(fs::read_to_string(cached_input_path(&config, 5)).unwrap(), "1 2 3\n");
+    }
+
+    #[test]
+    fn failed_download_is_not_cached() {
+        let (base_url, requests) = mock_server(400, "Please log in");
+        let config = test_config("failed", base_url);
+
+        assert!(fetch_input(&config, 1).unwrap_err().contains("status 400"));
+        assert!(!cached_input_path(&config, 1).exists());
+        assert_eq!(requests.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn missing_session_makes_no_request() {
+        let (base_url, requests) = mock_server(200, "unused");
+        let config = Config { session: None, ..test_config("session", base_url) };
+
+        assert!(fetch_input(&config, 1).unwrap_err().contains("No session token"));
+        assert!(requests.lock().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,168 @@
+//! Seeded generators of puzzle inputs, for stress-testing the solutions on inputs far larger than ours.
+//!
+//! Each generator builds its input from a model it controls, so where possible the answers are
+//! derived from that model rather than by running the solution they are meant to check.
+
+mod day_01;
+mod day_02;
+mod day_03;
+mod day_04;
+mod day_05;
+mod day_06;
+mod day_07;
+mod day_08;
+mod day_09;
+mod day_10;
+mod day_11;
+
+use aoc_common::{Answer, Part};
+
+/// SplitMix64, so the same seed yields the same input on every platform and in every version.
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in `low..=high`.
+    pub fn range(&mut self, low: u64, high: u64) -> u64 {
+        match (high - low).checked_add(1) {
+            Some(span) => low + self.next_u64() % span,
+            None => self.next_u64(),
+        }
+    }
+
+    /// Uniform in `low..=high`, for sizes and indices.
+    pub fn range_usize(&mut self, low: usize, high: usize) -> usize {
+        self.range(low as u64, high as u64) as usize
+    }
+
+    /// True with the given probability in percent.
+    pub fn percent(&mut self, percent: u64) -> bool {
+        self.next_u64() % 100 < percent
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.range_usize(0, items.len() - 1)]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.range_usize(0, i));
+        }
+    }
+}
+
+/// A generated input with the answers that could be derived without solving it.
+pub struct GeneratedInput {
+    pub input: String,
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+}
+
+impl GeneratedInput {
+    pub fn answer(&self, part: Part) -> Option<&Answer> {
+        match part {
+            Part::One => self.part1.as_ref(),
+            Part::Two => self.part2.as_ref(),
+        }
+    }
+}
+
+/// Generate an input for `day`. What `size` scales depends on the day, see the `aoc gen` help.
+pub fn generate(day: u8, seed: u64, size: usize) -> Result<GeneratedInput, String> {
+    if size == 0 {
+        return Err("Size must be at least 1".to_string());
+    }
+    let rng = &mut Rng::new(seed);
+    match day {
+        1 => Ok(day_01::generate(rng, size)),
+        2 => Ok(day_02::generate(rng, size)),
+        3 => day_03::generate(rng, size),
+        4 => Ok(day_04::generate(rng, size)),
+        5 => Ok(day_05::generate(rng, size)),
+        6 => day_06::generate(rng, size),
+        7 => day_07::generate(rng, size),
+        8 => day_08::generate(rng, size),
+        9 => day_09::generate(rng, size),
+        10 => day_10::generate(rng, size),
+        11 => day_11::generate(rng, size),
+        _ => Err(format!("There is no generator for day {}", day)),
+    }
+}
+
+/// The answers as an answers.toml section for the given input file name.
+pub fn answers_toml(day: u8, input_file_name: &str, generated: &GeneratedInput) -> String {
+    let mut toml = format!("[day_{:02}.{:?}]\n", day, input_file_name);
+    for part in Part::ALL {
+        if let Some(answer) = generated.answer(part) {
+            toml.push_str(&format!("part{} = \"{}\"\n", part.number(), answer));
+        }
+    }
+    toml
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+
+    /// Solve a small generated input of every day and compare against the generator's answers.
+    fn assert_solutions_agree(day: u8, size: usize) {
+        let solution = days::find(day).unwrap();
+        for seed in 0..3 {
+            let generated = generate(day, seed, size).unwrap();
+            for part in Part::ALL {
+                let Some(expected) = generated.answer(part) else { continue };
+                let answer = solution.solve(&generated.input, part).unwrap_or_else(|error| panic!("day {} seed {}: {}", day, seed, error));
+                assert_eq!(&answer, expected, "day {} part {} seed {}", day, part, seed);
+            }
+        }
+    }
+
+    #[test]
+    fn same_seed_same_input() {
+        for day in 1..=11 {
+            assert_eq!(generate(day, 7, 4).unwrap().input, generate(day, 7, 4).unwrap().input, "day {}", day);
+        }
+        assert_ne!(generate(7, 1, 4).unwrap().input, generate(7, 2, 4).unwrap().input);
+    }
+
+    #[test]
+    fn solutions_agree_with_generated_answers() {
+        for (day, size) in [(1, 50), (2, 50), (3, 30), (4, 50), (5, 1), (6, 4), (7, 200), (8, 200), (9, 50), (10, 20), (11, 30)] {
+            assert_solutions_agree(day, size);
+        }
+    }
+
+    #[test]
+    fn day_05_seed_ranges_cover_billions_of_seeds() {
+        let input = generate(5, 0, 5000).unwrap().input;
+        let numbers = input.lines().next().unwrap().split_whitespace().skip(1).map(|number| number.parse::<u64>().unwrap()).collect::<Vec<_>>();
+        let seeds = numbers.chunks(2).map(|range| range[1]).sum::<u64>();
+        assert!(seeds >= 2_000_000_000, "{} seeds", seeds);
+    }
+
+    #[test]
+    fn day_05_lowest_location_is_not_a_seed() {
+        for (seed, size) in (0..20).map(|seed| (seed, 1)).chain([(0, 5000), (1, 5000)]) {
+            let answer = generate(5, seed, size).unwrap().part2.unwrap();
+            assert_ne!(answer, Answer::from(0), "seed {} size {}", seed, size);
+        }
+    }
+
+    #[test]
+    fn answers_are_written_as_toml() {
+        let generated = GeneratedInput { input: String::new(), part1: Some(6440.into()), part2: None };
+        assert_eq!(answers_toml(7, "big.txt", &generated), "[day_07.\"big.txt\"]\npart1 = \"6440\"\n");
+    }
+}
@@ -0,0 +1,54 @@
+use super::{GeneratedInput, Rng};
+
+const WORDS: [&str; 9] = ["one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+/// Spelled-out digits sharing a letter, with the digits they stand for in order.
+const OVERLAPS: [(&str, [u32; 2]); 6] = [
+    ("twone", [2, 1]),
+    ("oneight", [1, 8]),
+    ("threeight", [3, 8]),
+    ("fiveight", [5, 8]),
+    ("eightwo", [8, 2]),
+    ("sevenine", [7, 9]),
+];
+/// Letters that occur in no digit word, so filler never spells one by accident.
+const FILLER: &[u8] = b"abcdjklmpqyz";
+
+/// `size` calibration lines, each holding at least one numeric digit.
+pub fn generate(rng: &mut Rng, size: usize) -> GeneratedInput {
+    let mut input = String::new();
+    let (mut part1, mut part2) = (0, 0);
+    for _ in 0..size {
+        let mut numeric_digits = vec![];
+        let mut all_digits = vec![];
+        let tokens = rng.range_usize(1, 6);
+        let numeric_token = rng.range_usize(0, tokens - 1);
+        for token in 0..tokens {
+            // filler between tokens keeps two words from running into a third one
+            for _ in 0..rng.range_usize(usize::from(token > 0), 4) {
+                input.push(*rng.pick(FILLER) as char);
+            }
+            if token == numeric_token || rng.percent(35) {
+                let digit = rng.range(1, 9) as u32;
+                input.push(char::from_digit(digit, 10).unwrap());
+                numeric_digits.push(digit);
+                all_digits.push(digit);
+            } else if rng.percent(20) {
+                let (word, digits) = rng.pick(&OVERLAPS);
+                input.push_str(word);
+                all_digits.extend(digits);
+            } else {
+                let digit = rng.range_usize(1, 9);
+                input.push_str(WORDS[digit - 1]);
+                all_digits.push(digit as u32);
+            }
+        }
+        input.push('\n');
+        part1 += calibration_value(&numeric_digits);
+        part2 += calibration_value(&all_digits);
+    }
+    GeneratedInput { input, part1: Some(part1.into()), part2: Some(part2.into()) }
+}
+
+fn calibration_value(digits: &[u32]) -> u32 {
+    digits[0] * 10 + digits[digits.len() - 1]
+}
@@ -0,0 +1,33 @@
+use super::{GeneratedInput, Rng};
+
+const COLORS: [&str; 3] = ["red", "green", "blue"];
+/// The bag of the puzzle's first part, in the order of `COLORS`.
+const BAG: [u32; 3] = [12, 13, 14];
+
+/// `size` games of one to six reveals.
+pub fn generate(rng: &mut Rng, size: usize) -> GeneratedInput {
+    let mut input = String::new();
+    let (mut part1, mut part2) = (0, 0);
+    for id in 1..=size {
+        let mut reveals = vec![];
+        let mut maximum = [0; 3];
+        for _ in 0..rng.range_usize(1, 6) {
+            let mut colors = [0, 1, 2];
+            rng.shuffle(&mut colors);
+            let cubes = colors[..rng.range_usize(1, 3)].iter()
+                .map(|&color| {
+                    let count = rng.range(1, 20) as u32;
+                    maximum[color] = maximum[color].max(count);
+                    format!("{} {}", count, COLORS[color])
+                })
+                .collect::<Vec<_>>();
+            reveals.push(cubes.join(", "));
+        }
+        input.push_str(&format!("Game {}: {}\n", id, reveals.join("; ")));
+        if maximum.iter().zip(BAG).all(|(&count, available)| count <= available) {
+            part1 += id as u32;
+        }
+        part2 += maximum.iter().product::<u32>();
+    }
+    GeneratedInput { input, part1: Some(part1.into()), part2: Some(part2.into()) }
+}
@@ -0,0 +1,66 @@
+use aoc_common::Grid;
+
+use super::{GeneratedInput, Rng};
+
+const SYMBOLS: &[u8] = b"#$%&*+-/=@";
+
+/// A number written into the schematic.
+struct PartNumber {
+    value: usize,
+    next_to_symbol: bool,
+    next_to_gear: bool,
+}
+
+/// A schematic of `size` by `size` cells.
+///
+/// Every number touches at most one `*` and every `*` at most two numbers, so which `*` is a gear
+/// does not depend on the order the symbols are looked at.
+pub fn generate(rng: &mut Rng, size: usize) -> Result<GeneratedInput, String> {
+    if size < 3 {
+        return Err("Day 3 needs a size of at least 3".to_string());
+    }
+    let mut schematic = Grid::new(size, vec!['.'; size * size]);
+    // index into `numbers` of the number covering a cell
+    let mut number_at = Grid::new(size, vec![None; size * size]);
+    let mut numbers = vec![];
+    for row in 0..size {
+        let mut column = rng.range_usize(0, 3);
+        while column < size {
+            let digits = rng.range_usize(1, 3).min(size - column);
+            let value = rng.range(10u64.pow(digits as u32 - 1), 10u64.pow(digits as u32) - 1) as usize;
+            for (i, digit) in value.to_string().chars().enumerate() {
+                schematic[(row, column + i)] = digit;
+                number_at[(row, column + i)] = Some(numbers.len());
+            }
+            numbers.push(PartNumber { value, next_to_symbol: false, next_to_gear: false });
+            // at least one cell between numbers, so they do not merge into one
+            column += digits + rng.range_usize(1, 5);
+        }
+    }
+
+    let mut gears = vec![];
+    for position in schematic.positions().collect::<Vec<_>>() {
+        if schematic[position] != '.' || !rng.percent(12) {
+            continue;
+        }
+        let mut touched = schematic.neighbors8(position).filter_map(|neighbor| number_at[neighbor]).collect::<Vec<_>>();
+        touched.sort_unstable();
+        touched.dedup();
+        let mut symbol = *rng.pick(SYMBOLS) as char;
+        if symbol == '*' && (touched.len() > 2 || touched.iter().any(|&number| numbers[number].next_to_gear)) {
+            symbol = '#';
+        }
+        for &number in &touched {
+            numbers[number].next_to_symbol = true;
+            numbers[number].next_to_gear |= symbol == '*';
+        }
+        if symbol == '*' && touched.len() == 2 {
+            gears.push(numbers[touched[0]].value * numbers[touched[1]].value);
+        }
+        schematic[position] = symbol;
+    }
+
+    let part1 = numbers.iter().filter(|number| number.next_to_symbol).map(|number| number.value).sum::<usize>();
+    let part2 = gears.iter().sum::<usize>();
+    Ok(GeneratedInput { input: format!("{}\n", schematic), part1: Some(part1.into()), part2: Some(part2.into()) })
+}
@@ -0,0 +1,37 @@
+use super::{GeneratedInput, Rng};
+
+const WINNING_NUMBERS: usize = 10;
+const NUMBERS_YOU_HAVE: usize = 25;
+/// A card held more often than this wins nothing, so the copies of later cards stay countable.
+const MAX_COPIES_TO_WIN: usize = 1_000_000;
+
+/// `size` scratchcards.
+pub fn generate(rng: &mut Rng, size: usize) -> GeneratedInput {
+    let mut input = String::new();
+    let mut copies = vec![1; size];
+    let (mut part1, mut part2) = (0usize, 0);
+    let id_width = size.to_string().len();
+    for id in 0..size {
+        // matches may only win copies of cards that exist
+        let mut matches = rng.range_usize(0, WINNING_NUMBERS).min(rng.range_usize(0, 5)).min(size - id - 1);
+        if copies[id] > MAX_COPIES_TO_WIN {
+            matches = 0;
+        }
+        let mut pool = (1..=99).collect::<Vec<usize>>();
+        rng.shuffle(&mut pool);
+        let (winning, rest) = pool.split_at(WINNING_NUMBERS);
+        let mut numbers = winning[..matches].iter().chain(&rest[..NUMBERS_YOU_HAVE - matches]).copied().collect::<Vec<_>>();
+        rng.shuffle(&mut numbers);
+
+        let format_numbers = |numbers: &[usize]| numbers.iter().map(|number| format!("{:>2}", number)).collect::<Vec<_>>().join(" ");
+        input.push_str(&format!("Card {:>width$}: {} | {}\n", id + 1, format_numbers(winning), format_numbers(&numbers), width = id_width));
+        if matches > 0 {
+            part1 += 1 << (matches - 1);
+        }
+        for next in id + 1..=id + matches {
+            copies[next] += copies[id];
+        }
+        part2 += copies[id];
+    }
+    GeneratedInput { input, part1: Some(part1.into()), part2: Some(part2.into()) }
+}
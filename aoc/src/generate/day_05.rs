@@ -0,0 +1,109 @@
+use std::ops::Range;
+
+use super::{GeneratedInput, Rng};
+
+const MAP_TITLES: [&str; 7] = [
+    "seed-to-soil map:",
+    "soil-to-fertilizer map:",
+    "fertilizer-to-water map:",
+    "water-to-light map:",
+    "light-to-temperature map:",
+    "temperature-to-humidity map:",
+    "humidity-to-location map:",
+];
+const SEED_RANGES: usize = 10;
+
+/// One line of a map: `length` values from `source` on are moved to `destination` on.
+#[derive(Clone, Copy)]
+struct Mapping {
+    destination: usize,
+    source: usize,
+    length: usize,
+}
+
+/// An almanac whose values all lie below `size` million, at most 2^32. The seed ranges cover
+/// most of that range between them, billions of seeds from a size of about 4000 on.
+///
+/// Every map shuffles whole segments of that range, so it is a bijection and a location leads
+/// back to exactly one seed no matter in which direction the maps are read. The lowest locations
+/// lead back to seeds no range covers, so a scan up from location 0 has a long way to go.
+pub fn generate(rng: &mut Rng, size: usize) -> GeneratedInput {
+    let universe = size.saturating_mul(1_000_000).min(1 << 32);
+    // the seeds below this are left out of every range
+    let uncovered = universe / 16;
+    let seed_ranges = (0..SEED_RANGES)
+        .map(|_| {
+            let length = rng.range_usize(universe / 16, universe / 8).max(1);
+            let start = rng.range_usize(uncovered, universe - length);
+            start..start + length
+        })
+        .collect::<Vec<_>>();
+    let mut maps = MAP_TITLES[1..].iter().map(|_| generate_map(rng, universe, 8 + size.min(40), None)).collect::<Vec<_>>();
+    // the last map sends the longest stretch the uncovered seeds end up on to the lowest locations
+    let humidities = maps[1..].iter().fold(map_range(&maps[0], 0..uncovered), |ranges, map| ranges.iter().flat_map(|range| map_range(map, range.clone())).collect());
+    let lowest = humidities.into_iter().max_by_key(|range| range.len());
+    maps.push(generate_map(rng, universe, 8 + size.min(40), lowest));
+
+    let mut input = String::from("seeds:");
+    for range in &seed_ranges {
+        input.push_str(&format!(" {} {}", range.start, range.len()));
+    }
+    input.push('\n');
+    for (title, map) in MAP_TITLES.iter().zip(&maps) {
+        input.push_str(&format!("\n{}\n", title));
+        for mapping in map {
+            input.push_str(&format!("{} {} {}\n", mapping.destination, mapping.source, mapping.length));
+        }
+    }
+
+    // the first part reads every number on the seeds line as a seed
+    let part1 = seed_ranges.iter()
+        .flat_map(|range| [range.start, range.len()])
+        .map(|seed| maps.iter().fold(seed, |value, map| map_value(map, value)))
+        .min();
+    let locations = maps.iter().fold(seed_ranges, |ranges, map| ranges.iter().flat_map(|range| map_range(map, range.clone())).collect());
+    let part2 = locations.iter().map(|range| range.start).min();
+    GeneratedInput { input, part1: part1.map(Into::into), part2: part2.map(Into::into) }
+}
+
+/// Cut `0..universe` into segments and lay them out again in a random order, with the segments
+/// of `first` laid out first when given.
+fn generate_map(rng: &mut Rng, universe: usize, segments: usize, first: Option<Range<usize>>) -> Vec<Mapping> {
+    let mut cuts = (1..segments).map(|_| rng.range_usize(1, universe - 1)).collect::<Vec<_>>();
+    cuts.extend([0, universe]);
+    cuts.extend(first.iter().flat_map(|first| [first.start, first.end]));
+    cuts.sort_unstable();
+    cuts.dedup();
+    let mut sources = cuts.windows(2).map(|cut| cut[0]..cut[1]).collect::<Vec<_>>();
+    rng.shuffle(&mut sources);
+    if let Some(first) = &first {
+        sources.sort_by_key(|source| !(first.start <= source.start && source.end <= first.end));
+    }
+    let mut destination = 0;
+    let mut map = sources.into_iter()
+        .map(|source| {
+            let mapping = Mapping { destination, source: source.start, length: source.len() };
+            destination += source.len();
+            mapping
+        })
+        .collect::<Vec<_>>();
+    rng.shuffle(&mut map);
+    map
+}
+
+fn map_value(map: &[Mapping], value: usize) -> usize {
+    map.iter()
+        .find(|mapping| (mapping.source..mapping.source + mapping.length).contains(&value))
+        .map_or(value, |mapping| value - mapping.source + mapping.destination)
+}
+
+/// The ranges `range` is mapped to, one for every segment of the map it overlaps.
+fn map_range(map: &[Mapping], range: Range<usize>) -> Vec<Range<usize>> {
+    map.iter()
+        .filter_map(|mapping| {
+            let start = range.start.max(mapping.source);
+            let end = range.end.min(mapping.source + mapping.length);
+            (start < end).then(|| start - mapping.source + mapping.destination..end - mapping.source + mapping.destination)
+        })
+        .collect()
+}
@@ -0,0 +1,55 @@
+use super::{GeneratedInput, Rng};
+
+/// Beyond four races the concatenated race of the second part no longer fits the solution's `usize` maths.
+const MAX_RACES: usize = 4;
+
+/// `size` races of up to 99 milliseconds, each with a record that can be beaten.
+pub fn generate(rng: &mut Rng, size: usize) -> Result<GeneratedInput, String> {
+    if size > MAX_RACES {
+        return Err(format!("Day 6 supports at most {} races", MAX_RACES));
+    }
+    let races = (0..size)
+        .map(|_| {
+            let duration = rng.range(7, 99);
+            (duration, rng.range(0, best_distance(duration.into()) as u64 - 1))
+        })
+        .collect::<Vec<_>>();
+
+    let column = |values: Vec<u64>| values.iter().map(|value| format!("{:>5}", value)).collect::<String>();
+    let input = format!(
+        "Time:    {}\nDistance:{}\n",
+        column(races.iter().map(|&(duration, _)| duration).collect()),
+        column(races.iter().map(|&(_, record)| record).collect()),
+    );
+    let part1 = races.iter().map(|&(duration, record)| ways_to_win(duration, record)).product::<u128>();
+    let concatenate = |values: Vec<u64>| values.iter().map(u64::to_string).collect::<String>().parse::<u64>().unwrap();
+    let part2 = ways_to_win(
+        concatenate(races.iter().map(|&(duration, _)| duration).collect()),
+        concatenate(races.iter().map(|&(_, record)| record).collect()),
+    );
+    Ok(GeneratedInput { input, part1: Some(part1.into()), part2: Some(part2.into()) })
+}
+
+fn best_distance(duration: u128) -> u128 {
+    let charge = duration / 2;
+    charge * (duration - charge)
+}
+
+/// Charge times beating the record, found by binary search for the shortest one.
+/// The distance is symmetric around half the race, so the longest one mirrors it.
+fn ways_to_win(duration: u64, record: u64) -> u128 {
+    let (duration, record) = (u128::from(duration), u128::from(record));
+    if best_distance(duration) <= record {
+        return 0;
+    }
+    let (mut low, mut high) = (0, duration / 2);
+    while low < high {
+        let charge = (low + high) / 2;
+        if charge * (duration - charge) > record {
+            high = charge;
+        } else {
+            low = charge + 1;
+        }
+    }
+    duration - 2 * low + 1
+}
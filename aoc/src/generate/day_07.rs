@@ -0,0 +1,60 @@
+use std::collections::HashSet;
+
+use super::{GeneratedInput, Rng};
+
+const LABELS: &[u8] = b"23456789TJQKA";
+const JOKER_LABELS: &[u8] = b"J23456789TQKA";
+/// Generating distinct hands slows down as they run out, so stay well below the 13^5 there are.
+const MAX_HANDS: usize = 100_000;
+
+/// `size` distinct hands, so no two of them tie for a rank.
+pub fn generate(rng: &mut Rng, size: usize) -> Result<GeneratedInput, String> {
+    if size > MAX_HANDS {
+        return Err(format!("Day 7 supports at most {} hands", MAX_HANDS));
+    }
+    let mut seen = HashSet::new();
+    let mut hands = vec![];
+    while hands.len() < size {
+        // drawing from a few labels only makes pairs and full houses as likely as high cards
+        let mut labels = LABELS.to_vec();
+        rng.shuffle(&mut labels);
+        let labels = &labels[..rng.range_usize(1, 5)];
+        let cards = (0..5).map(|_| *rng.pick(labels)).collect::<Vec<u8>>();
+        if seen.insert(cards.clone()) {
+            hands.push((cards, rng.range(1, 1000) as usize));
+        }
+    }
+
+    let input = hands.iter().map(|(cards, bid)| format!("{} {}\n", String::from_utf8_lossy(cards), bid)).collect();
+    let part1 = total_winnings(&hands, LABELS, false);
+    let part2 = total_winnings(&hands, JOKER_LABELS, true);
+    Ok(GeneratedInput { input, part1: Some(part1.into()), part2: Some(part2.into()) })
+}
+
+fn total_winnings(hands: &[(Vec<u8>, usize)], order: &[u8], jokers: bool) -> usize {
+    let mut ranked = hands.iter()
+        .map(|(cards, bid)| {
+            let strengths = cards.iter().map(|card| order.iter().position(|label| label == card).unwrap()).collect::<Vec<_>>();
+            ((hand_type(cards, jokers), strengths), bid)
+        })
+        .collect::<Vec<_>>();
+    ranked.sort_unstable();
+    ranked.iter().enumerate().map(|(rank, (_, &bid))| (rank + 1) * bid).sum()
+}
+
+/// Sizes of the groups of equal labels, largest first, which order hands by type when compared.
+/// Jokers join the largest group, which always makes the strongest type.
+fn hand_type(cards: &[u8], jokers: bool) -> Vec<usize> {
+    let joker_count = if jokers { cards.iter().filter(|&&card| card == b'J').count() } else { 0 };
+    let mut groups = LABELS.iter()
+        .filter(|&&label| !jokers || label != b'J')
+        .map(|label| cards.iter().filter(|&card| card == label).count())
+        .filter(|&count| count > 0)
+        .collect::<Vec<_>>();
+    groups.sort_unstable_by(|a, b| b.cmp(a));
+    match groups.first_mut() {
+        Some(largest) => *largest += joker_count,
+        None => groups.push(joker_count),
+    }
+    groups
+}
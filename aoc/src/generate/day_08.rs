@@ -0,0 +1,62 @@
+use std::collections::HashSet;
+
+use super::{GeneratedInput, Rng};
+
+/// Each ghost's walk is the instructions repeated this many times, distinct primes so the walks only line up at their product.
+const PRIMES: [usize; 6] = [3, 5, 7, 11, 13, 17];
+const NAME_CHARACTERS: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+/// Names of the walked nodes may not end in A or Z, which leaves 36 * 36 * 34 of them.
+const MAX_NODES: usize = 40_000;
+
+/// A network of about `size` nodes with one ghost per entry of `PRIMES`.
+///
+/// Every ghost walks a chain from its start to its end node and the end node leads back to the
+/// start of the chain, so the steps to the first end node are also the length of the cycle.
+pub fn generate(rng: &mut Rng, size: usize) -> Result<GeneratedInput, String> {
+    if size > MAX_NODES {
+        return Err(format!("Day 8 supports at most {} nodes", MAX_NODES));
+    }
+    let instruction_count = (size.saturating_sub(2 * PRIMES.len()) / PRIMES.iter().sum::<usize>()).max(1);
+    let instructions = (0..instruction_count).map(|_| if rng.percent(50) { 'L' } else { 'R' }).collect::<String>();
+
+    let mut names = NAME_CHARACTERS.iter()
+        .flat_map(|&a| NAME_CHARACTERS.iter().flat_map(move |&b| NAME_CHARACTERS.iter().map(move |&c| [a, b, c])))
+        .map(|name| String::from_utf8_lossy(&name).into_owned())
+        .collect::<Vec<_>>();
+    rng.shuffle(&mut names);
+    let (mut walked_names, mut end_names): (Vec<_>, Vec<_>) = names.into_iter().partition(|name| !name.ends_with(['A', 'Z']));
+    // AA and ZZ belong to the first ghost
+    end_names.retain(|name| !name.starts_with("AA") && !name.starts_with("ZZ"));
+    let mut used_ends = HashSet::new();
+
+    let mut lines = vec![];
+    for (ghost, &prime) in PRIMES.iter().enumerate() {
+        let steps = instruction_count * prime;
+        let (start, end) = if ghost == 0 {
+            ("AAA".to_string(), "ZZZ".to_string())
+        } else {
+            // start and end of a ghost share their first two characters, like in the puzzle
+            let end = end_names.iter().find(|name| name.ends_with('Z') && !used_ends.contains(&name[..2])).unwrap().clone();
+            used_ends.insert(end[..2].to_string());
+            (format!("{}A", &end[..2]), end)
+        };
+        // the nodes reached after 1 to steps - 1 steps
+        let chain = walked_names.split_off(walked_names.len() - (steps - 1));
+        let node_after = |step: usize| if step == steps { &end } else { &chain[step - 1] };
+        lines.push(format!("{} = ({}, {})", start, node_after(1), node_after(1)));
+        for step in 1..steps {
+            // step + 1 follows the instruction at step modulo their count, the other side leads back into the chain
+            let next = node_after(step + 1);
+            let elsewhere = &chain[rng.range_usize(0, chain.len() - 1)];
+            let (left, right) = if instructions.as_bytes()[step % instruction_count] == b'L' { (next, elsewhere) } else { (elsewhere, next) };
+            lines.push(format!("{} = ({}, {})", chain[step - 1], left, right));
+        }
+        lines.push(format!("{} = ({}, {})", end, node_after(1), node_after(1)));
+    }
+    rng.shuffle(&mut lines);
+
+    let input = format!("{}\n\n{}\n", instructions, lines.join("\n"));
+    let part1 = instruction_count * PRIMES[0];
+    let part2 = instruction_count * PRIMES.iter().product::<usize>();
+    Ok(GeneratedInput { input, part1: Some(part1.into()), part2: Some(part2.into()) })
+}
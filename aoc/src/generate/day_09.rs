@@ -0,0 +1,35 @@
+use super::{GeneratedInput, Rng};
+
+const VALUES_PER_HISTORY: usize = 21;
+const MAX_DEGREE: usize = 4;
+/// The solution sums in `i32`, so the coefficients shrink as histories are added.
+const MAX_HISTORIES: usize = 200_000;
+
+/// `size` histories of polynomials of degree at most four.
+///
+/// A polynomial given in Newton form, `sum of a_k * binomial(n, k)`, has the coefficients as the
+/// first values of its difference sequences, so both extrapolations follow from them directly.
+pub fn generate(rng: &mut Rng, size: usize) -> Result<GeneratedInput, String> {
+    if size > MAX_HISTORIES {
+        return Err(format!("Day 9 supports at most {} histories", MAX_HISTORIES));
+    }
+    let bound = (200_000 / size).max(1) as i64;
+    let mut input = String::new();
+    let (mut part1, mut part2) = (0, 0);
+    for _ in 0..size {
+        let degree = rng.range_usize(0, MAX_DEGREE);
+        let coefficients = (0..=degree).map(|_| rng.range(0, 2 * bound as u64) as i64 - bound).collect::<Vec<_>>();
+        let value_at = |n: i64| coefficients.iter().enumerate().map(|(k, coefficient)| coefficient * binomial(n, k)).sum::<i64>();
+        let history = (0..VALUES_PER_HISTORY as i64).map(|n| value_at(n).to_string()).collect::<Vec<_>>();
+        input.push_str(&history.join(" "));
+        input.push('\n');
+        part1 += value_at(VALUES_PER_HISTORY as i64);
+        part2 += value_at(-1);
+    }
+    Ok(GeneratedInput { input, part1: Some(part1.into()), part2: Some(part2.into()) })
+}
+
+/// `n` choose `k`, for negative `n` too.
+fn binomial(n: i64, k: usize) -> i64 {
+    (0..k as i64).fold(1, |product, i| product * (n - i) / (i + 1))
+}
@@ -0,0 +1,73 @@
+use aoc_common::{Direction, Grid, Point};
+
+use super::{GeneratedInput, Rng};
+
+const JUNK: &[u8] = b"|-LJ7F...";
+
+/// A `size` by `size` field with one loop and random pipes around it.
+///
+/// The loop runs around a blob of unit squares between tile centers, built column by column with
+/// every column overlapping its neighbor so the outline never touches itself. The loop is that
+/// outline, and Pick's theorem gives the tiles it encloses: `squares - loop / 2 + 1`.
+pub fn generate(rng: &mut Rng, size: usize) -> Result<GeneratedInput, String> {
+    if size < 3 {
+        return Err("Day 10 needs a size of at least 3".to_string());
+    }
+    // squares are addressed by the tile at their top left corner
+    let square_rows = size - 1;
+    let columns = rng.range_usize(1, size - 1);
+    let first_column = rng.range_usize(0, size - 1 - columns);
+    let mut blob = Grid::new(size, vec![false; size * size]);
+    let mut top = rng.range_usize(0, square_rows - 1);
+    let mut bottom = rng.range_usize(top + 1, square_rows);
+    for column in first_column..first_column + columns {
+        for row in top..bottom {
+            blob[(row, column)] = true;
+        }
+        let next_top = rng.range_usize(top.saturating_sub(2), bottom - 1);
+        bottom = rng.range_usize(next_top.max(top) + 1, square_rows.min(bottom + 2));
+        top = next_top;
+    }
+    let squares = blob.iter().filter(|(_, &inside)| inside).count();
+
+    let in_blob = |point: Point| blob.get_point(point).copied().unwrap_or(false);
+    let pipes = blob.map(|position, _| {
+        let tile = Point::from_row_column(position);
+        // the loop runs along every edge from a tile with the blob on one side only
+        let openings = Direction::ALL.map(|direction| {
+            let sides = match direction {
+                Direction::North => [tile + Point::new(-1, -1), tile + Point::new(0, -1)],
+                Direction::East => [tile + Point::new(0, -1), tile],
+                Direction::South => [tile + Point::new(-1, 0), tile],
+                Direction::West => [tile + Point::new(-1, -1), tile + Point::new(-1, 0)],
+            };
+            in_blob(sides[0]) != in_blob(sides[1])
+        });
+        match openings {
+            [true, false, true, false] => Some('|'),
+            [false, true, false, true] => Some('-'),
+            [true, true, false, false] => Some('L'),
+            [true, false, false, true] => Some('J'),
+            [false, false, true, true] => Some('7'),
+            [false, true, true, false] => Some('F'),
+            _ => None,
+        }
+    });
+    let loop_length = pipes.iter().filter(|(_, pipe)| pipe.is_some()).count();
+
+    let start_candidates = pipes.find_all(|pipe| pipe.is_some()).collect::<Vec<_>>();
+    let start = *rng.pick(&start_candidates);
+    let start_neighbors = pipes.neighbors4(start).collect::<Vec<_>>();
+    let mut field = pipes.map(|_, pipe| pipe.unwrap_or_else(|| *rng.pick(JUNK) as char));
+    for neighbor in start_neighbors {
+        // junk opening towards the start could pass for a second way around
+        if pipes[neighbor].is_none() {
+            field[neighbor] = '.';
+        }
+    }
+    field[start] = 'S';
+
+    let part1 = loop_length / 2;
+    let part2 = squares + 1 - loop_length / 2;
+    Ok(GeneratedInput { input: format!("{}\n", field), part1: Some(part1.into()), part2: Some(part2.into()) })
+}
@@ -0,0 +1,49 @@
+use super::{GeneratedInput, Rng};
+
+/// A `size` by `size` image with a few galaxies and some empty rows and columns.
+pub fn generate(rng: &mut Rng, size: usize) -> Result<GeneratedInput, String> {
+    if size < 2 {
+        return Err("Day 11 needs a size of at least 2".to_string());
+    }
+    let empty_rows = (0..size).map(|_| rng.percent(15)).collect::<Vec<_>>();
+    let empty_columns = (0..size).map(|_| rng.percent(15)).collect::<Vec<_>>();
+    let mut galaxies = vec![];
+    let mut input = String::new();
+    for (row, &empty_row) in empty_rows.iter().enumerate() {
+        for (column, &empty_column) in empty_columns.iter().enumerate() {
+            if !empty_row && !empty_column && rng.percent(6) {
+                galaxies.push((row, column));
+                input.push('#');
+            } else {
+                input.push('.');
+            }
+        }
+        input.push('\n');
+    }
+
+    let rows = galaxies.iter().map(|&(row, _)| row).collect::<Vec<_>>();
+    let columns = galaxies.iter().map(|&(_, column)| column).collect::<Vec<_>>();
+    let sum_of_distances = |expansion_factor: u64| {
+        sum_of_pairwise_distances(&rows, expansion_factor) + sum_of_pairwise_distances(&columns, expansion_factor)
+    };
+    Ok(GeneratedInput { input, part1: Some(sum_of_distances(2).into()), part2: Some(sum_of_distances(1_000_000).into()) })
+}
+
+/// Sum of the distances between all pairs of coordinates on one axis, once the lines without a
+/// galaxy are `expansion_factor` times as wide.
+fn sum_of_pairwise_distances(coordinates: &[usize], expansion_factor: u64) -> u64 {
+    let mut sorted = coordinates.to_vec();
+    sorted.sort_unstable();
+    let mut expanded = Vec::with_capacity(sorted.len());
+    let mut empty_lines = 0;
+    for (i, &coordinate) in sorted.iter().enumerate() {
+        if i > 0 {
+            empty_lines += (coordinate - sorted[i - 1]).saturating_sub(1) as u64;
+        }
+        expanded.push(coordinate as u64 + empty_lines * (expansion_factor - 1));
+    }
+    // in sorted order the i-th coordinate is the larger one of i pairs and the smaller one of the rest
+    let count = expanded.len() as u64;
+    expanded.iter().enumerate().map(|(i, &coordinate)| coordinate * i as u64).sum::<u64>()
+        - expanded.iter().enumerate().map(|(i, &coordinate)| coordinate * (count - 1 - i as u64)).sum::<u64>()
+}
@@ -0,0 +1,441 @@
+mod bench;
+mod calendar;
+mod config;
+mod days;
+mod differential;
+mod fetch;
+mod generate;
+#[cfg(test)]
+mod mock_server;
+mod output;
+mod run_all;
+mod scaffold;
+mod submit;
+
+use std::{fs, io::Write, panic, path::PathBuf, process::ExitCode};
+
+use aoc_common::{workspace_root, ExpectedAnswers, InputSource, Part, Verdict};
+use bench::BenchOptions;
+use clap::{Parser, Subcommand};
+use config::Config;
+use fetch::Installed;
+use output::{OutputFormat, RunRecord};
+use submit::{Outcome, SubmissionLog};
+
+#[cfg(feature = "count-allocations")]
+#[global_allocator]
+static ALLOCATOR: aoc_common::CountingAllocator = aoc_common::CountingAllocator;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Show more diagnostics, repeat for more detail (overridden by the AOC_LOG filter)
+    #[arg(short, long, action = clap::ArgAction::Count, global = true)]
+    verbose: u8,
+    /// Write the diagnostics as JSON lines to this file instead of stderr
+    #[arg(long, global = true)]
+    log_file: Option<PathBuf>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one or both parts of a day, or of every day in parallel
+    Run {
+        #[arg(long, required_unless_present = "all")]
+        day: Option<u8>,
+        /// Run every day on a thread pool and print a summary table
+        #[arg(long, conflicts_with_all = ["day", "input"])]
+        all: bool,
+        /// Part to solve, both parts when omitted
+        #[arg(long)]
+        part: Option<Part>,
+        /// Input file or "-" for stdin, defaults to the embedded input or the day's src/input.txt
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Print machine-readable records instead of text, with nothing else on stdout
+        #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
+    },
+    /// Solve every day (or a single one) and compare against the expected answers
+    Verify {
+        #[arg(long)]
+        day: Option<u8>,
+        /// Expected answers file, defaults to answers.toml in the workspace root
+        #[arg(long)]
+        answers: Option<PathBuf>,
+    },
+    /// Time the parse, part 1 and part 2 phases and compare them against a saved baseline
+    Bench {
+        #[arg(long)]
+        day: Option<u8>,
+        /// Repetitions per day, the fastest run is reported
+        #[arg(long, default_value_t = 5)]
+        runs: usize,
+        /// Baseline file, defaults to target/aoc-bench/baseline.json
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+        /// Store the timings of this run as the new baseline
+        #[arg(long)]
+        save_baseline: bool,
+        /// Flag phases that got slower than the baseline by more than this percentage
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+    /// Download a day's input into the cache and install it as the day's src/input.txt
+    Fetch {
+        #[arg(long)]
+        day: u8,
+        /// Puzzle year, defaults to `year` in aoc.toml or 2023
+        #[arg(long)]
+        year: Option<u16>,
+        /// Replace an existing src/input.txt that differs from the download
+        #[arg(long)]
+        force: bool,
+    },
+    /// Solve a part and submit the answer, unless the submission log already rules it out
+    Submit {
+        #[arg(long)]
+        day: u8,
+        #[arg(long)]
+        part: Part,
+    },
+    /// Create the crate for a new day from a template and register it in the workspace and this runner
+    New {
+        day: u8,
+    },
+    /// Write a random but valid input for a day, the same one for the same seed and size
+    Gen {
+        #[arg(long)]
+        day: u8,
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// Lines, grid side length, races or nodes, depending on the day
+        #[arg(long)]
+        size: usize,
+        /// File to write the input to, stdout when omitted
+        #[arg(long)]
+        output: Option<PathBuf>,
+        /// Append the answers the generator knows to this answers file, keyed by the output file name
+        #[arg(long, requires = "output")]
+        answers: Option<PathBuf>,
+    },
+    /// Draw the year's 25 days with their stars, verification status and run time
+    Calendar,
+    /// Solve generated inputs with every implementation of a part and report the first one they disagree on
+    Diff {
+        /// Only compare this day's implementations
+        #[arg(long)]
+        day: Option<u8>,
+        #[arg(long)]
+        part: Option<Part>,
+        /// Number of inputs to generate, from seed 0 on
+        #[arg(long, default_value_t = 100)]
+        seeds: u64,
+        /// Input size passed to the generator, defaults to one the slowest implementation handles quickly
+        #[arg(long)]
+        size: Option<usize>,
+    },
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let log_file = match cli.log_file.as_ref().map(fs::File::create).transpose() {
+        Ok(log_file) => log_file,
+        Err(error) => {
+            eprintln!("Failed to create {} - error: {}", cli.log_file.unwrap_or_default().display(), error);
+            return ExitCode::FAILURE;
+        }
+    };
+    aoc_common::init_tracing(cli.verbose, log_file);
+    match cli.command {
+        Command::Run { all: true, part, format, .. } => {
+            run_all::run_all(&part.map(|part| vec![part]).unwrap_or(Part::ALL.to_vec()), format)
+        }
+        Command::Run { day, part, input, format, .. } => run(day.unwrap_or_default(), part, input, format),
+        Command::Verify { day, answers } => verify(day, answers),
+        Command::Bench { day, runs, baseline, save_baseline, threshold } => {
+            bench::bench(BenchOptions { day, runs, baseline, save_baseline, threshold })
+        }
+        Command::Fetch { day, year, force } => fetch(day, year, force),
+        Command::Submit { day, part } => submit(day, part),
+        Command::New { day } => new_day(day),
+        Command::Gen { day, seed, size, output, answers } => generate_input(day, seed, size, output, answers),
+        Command::Calendar => calendar::calendar(),
+        Command::Diff { day, part, seeds, size } => diff(day, part, seeds, size),
+    }
+}
+
+fn run(day: u8, part: Option<Part>, input: Option<PathBuf>, format: OutputFormat) -> ExitCode {
+    let Some(solution) = days::find(day) else {
+        eprintln!("Day {} is not implemented", day);
+        return ExitCode::FAILURE;
+    };
+    let input_source = input.map(InputSource::from_arg).unwrap_or_else(|| solution.default_input());
+    let input_string = match input_source.read() {
+        Ok(input_string) => input_string,
+        Err(error) => {
+            eprintln!("{}", error);
+            return ExitCode::FAILURE;
+        }
+    };
+    let parts = part.map(|part| vec![part]).unwrap_or(Part::ALL.to_vec());
+    let day_run = match solution.run(&input_string, &parts) {
+        Ok(day_run) => day_run,
+        Err(error) => {
+            eprintln!("{}", error.with_input_file(input_source.display_path()));
+            return ExitCode::FAILURE;
+        }
+    };
+    if format == OutputFormat::Text {
+        for part_run in &day_run.parts {
+            println!("Day {} Part {}: {}", day, part_run.part, part_run.answer);
+        }
+        if let Some(stats) = &day_run.parse_allocations {
+            println!("Parse: {}", bench::format_allocations(stats));
+        }
+        for part_run in &day_run.parts {
+            if let Some(stats) = &part_run.allocations {
+                println!("Part {}: {}", part_run.part, bench::format_allocations(stats));
+            }
+        }
+        return ExitCode::SUCCESS;
+    }
+
+    // a missing answers file only means the answers cannot be checked, so it must not abort the run
+    let expected_answers = ExpectedAnswers::load(ExpectedAnswers::default_path()).unwrap_or_else(|error| {
+        eprintln!("{}", error);
+        ExpectedAnswers::default()
+    });
+    let input_sha256 = output::sha256_hex(&input_string);
+    let records = day_run.parts.iter()
+        .map(|part_run| RunRecord {
+            day,
+            part: part_run.part.number(),
+            answer: part_run.answer.to_string(),
+            elapsed_ns: part_run.elapsed.as_nanos() as u64,
+            input: input_source.to_string(),
+            input_sha256: input_sha256.clone(),
+            matches_expected: input_source.file_name()
+                .and_then(|file_name| expected_answers.verify(day, part_run.part, &file_name, &part_run.answer).matches()),
+            allocations: part_run.allocations,
+            parse_elapsed_ns: day_run.parse_elapsed.as_nanos() as u64,
+            parse_allocations: day_run.parse_allocations,
+        })
+        .collect::<Vec<_>>();
+    output::print_records(format, &records);
+    ExitCode::SUCCESS
+}
+
+fn verify(day: Option<u8>, answers: Option<PathBuf>) -> ExitCode {
+    let answers_path = answers.unwrap_or_else(ExpectedAnswers::default_path);
+    let expected_answers = match ExpectedAnswers::load(&answers_path) {
+        Ok(expected_answers) => expected_answers,
+        Err(error) => {
+            eprintln!("{}", error);
+            return ExitCode::FAILURE;
+        }
+    };
+    let solutions = days::all().into_iter().filter(|solution| day.is_none_or(|day| solution.day == day)).collect::<Vec<_>>();
+    if solutions.is_empty() {
+        eprintln!("Day {} is not implemented", day.unwrap_or_default());
+        return ExitCode::FAILURE;
+    }
+
+    let mut failures = 0;
+    for solution in solutions {
+        let input_source = solution.default_input();
+        let input_string = match input_source.read() {
+            Ok(input_string) => input_string,
+            Err(error) => {
+                println!("Day {:>2}: FAIL {}", solution.day, error);
+                failures += 1;
+                continue;
+            }
+        };
+        for part in Part::ALL {
+            // a panicking solution must not keep the remaining days from being verified
+            let result = panic::catch_unwind(|| solution.solve(&input_string, part));
+            let answer = match result {
+                Ok(Ok(answer)) => answer,
+                Ok(Err(error)) => {
+                    println!("Day {:>2} Part {}: FAIL {}", solution.day, part, error.with_input_file(input_source.display_path()));
+                    failures += 1;
+                    continue;
+                }
+                Err(_) => {
+                    println!("Day {:>2} Part {}: FAIL solution panicked", solution.day, part);
+                    failures += 1;
+                    continue;
+                }
+            };
+            match expected_answers.verify(solution.day, part, "input.txt", &answer) {
+                Verdict::Pass => println!("Day {:>2} Part {}: PASS {}", solution.day, part, answer),
+                Verdict::Fail { expected } => {
+                    println!("Day {:>2} Part {}: FAIL expected {}, got {}", solution.day, part, expected, answer);
+                    failures += 1;
+                }
+                Verdict::Unknown => println!("Day {:>2} Part {}: UNKNOWN {}", solution.day, part, answer),
+            }
+        }
+    }
+    if failures > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn fetch(day: u8, year: Option<u16>, force: bool) -> ExitCode {
+    let mut config = match Config::load(&Config::default_path()) {
+        Ok(config) => config,
+        Err(error) => {
+            eprintln!("{}", error);
+            return ExitCode::FAILURE;
+        }
+    };
+    if let Some(year) = year {
+        config.year = year;
+    }
+    let result = fetch::fetch_input(&config, day).and_then(|contents| fetch::install_input(day, &contents, force));
+    match result {
+        Ok(Installed::Written(path)) => println!("Day {}: input written to {}", day, path.display()),
+        Ok(Installed::Unchanged(path)) => println!("Day {}: {} is up to date", day, path.display()),
+        Err(error) => {
+            eprintln!("Day {}: {}", day, error);
+            return ExitCode::FAILURE;
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+fn submit(day: u8, part: Part) -> ExitCode {
+    let Some(solution) = days::find(day) else {
+        eprintln!("Day {} is not implemented", day);
+        return ExitCode::FAILURE;
+    };
+    let input_source = solution.default_input();
+    let answer = match input_source.read().and_then(|input_string| solution.solve(&input_string, part)) {
+        Ok(answer) => answer.to_string(),
+        Err(error) => {
+            eprintln!("{}", error.with_input_file(input_source.display_path()));
+            return ExitCode::FAILURE;
+        }
+    };
+    let result = Config::load(&Config::default_path()).and_then(|config| {
+        let mut log = SubmissionLog::load(&SubmissionLog::default_path(&config))?;
+        submit::submit_answer(&config, &mut log, day, part.number(), &answer, submit::unix_now())
+    });
+    match result {
+        Ok(submission) => {
+            println!("Day {} Part {}: {} is {}", day, part, answer, submission.outcome.describe());
+            if submission.outcome == Outcome::Correct {
+                ExitCode::SUCCESS
+            } else {
+                if submission.wait_secs > 0 {
+                    println!("Next submission allowed in {}s", submission.wait_secs);
+                }
+                ExitCode::FAILURE
+            }
+        }
+        Err(error) => {
+            eprintln!("Day {} Part {}: {}", day, part, error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn new_day(day: u8) -> ExitCode {
+    match scaffold::new_day(&workspace_root(), day) {
+        Ok(written) => {
+            for path in written {
+                println!("wrote {}", path.display());
+            }
+            println!("Day {}: fill in src/input_example.txt and the example answers, then run `aoc fetch --day {}`", day, day);
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("{}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn generate_input(day: u8, seed: u64, size: usize, output: Option<PathBuf>, answers: Option<PathBuf>) -> ExitCode {
+    let generated = match generate::generate(day, seed, size) {
+        Ok(generated) => generated,
+        Err(error) => {
+            eprintln!("{}", error);
+            return ExitCode::FAILURE;
+        }
+    };
+    let Some(output) = output else {
+        print!("{}", generated.input);
+        return ExitCode::SUCCESS;
+    };
+    if let Err(error) = fs::write(&output, &generated.input) {
+        eprintln!("Failed to write {} - error: {}", output.display(), error);
+        return ExitCode::FAILURE;
+    }
+    println!("Day {}: input written to {}", day, output.display());
+    for part in Part::ALL.into_iter().filter(|&part| generated.answer(part).is_none()) {
+        eprintln!("Day {} Part {}: the answer cannot be derived from the generator", day, part);
+    }
+    if let Some(answers) = answers {
+        let file_name = output.file_name().unwrap_or_default().to_string_lossy();
+        let section = generate::answers_toml(day, &file_name, &generated);
+        let result = fs::OpenOptions::new().create(true).append(true).open(&answers)
+            .and_then(|mut file| write!(file, "\n{}", section));
+        if let Err(error) = result {
+            eprintln!("Failed to write {} - error: {}", answers.display(), error);
+            return ExitCode::FAILURE;
+        }
+        println!("Day {}: answers appended to {}", day, answers.display());
+    }
+    ExitCode::SUCCESS
+}
+
+fn diff(day: Option<u8>, part: Option<Part>, seeds: u64, size: Option<usize>) -> ExitCode {
+    let candidates = differential::all().into_iter()
+        .filter(|candidates| day.is_none_or(|day| candidates.day == day) && part.is_none_or(|part| candidates.part == part))
+        .collect::<Vec<_>>();
+    if candidates.is_empty() {
+        eprintln!("No part matching the filter has more than one implementation");
+        return ExitCode::FAILURE;
+    }
+    let mut failures = 0;
+    for candidates in candidates {
+        let (day, part) = (candidates.day, candidates.part);
+        let disagreement = match candidates.compare(0..seeds, size.unwrap_or(candidates.size)) {
+            Ok(None) => {
+                println!("Day {:>2} Part {}: {} implementations agree on {} inputs", day, part, candidates.alternatives.len() + 1, seeds);
+                continue;
+            }
+            Ok(Some(disagreement)) => disagreement,
+            Err(error) => {
+                eprintln!("Day {:>2} Part {}: {}", day, part, error);
+                failures += 1;
+                continue;
+            }
+        };
+        failures += 1;
+        println!("Day {:>2} Part {}: implementations disagree on seed {}", day, part, disagreement.seed);
+        for (name, answer) in &disagreement.answers {
+            match answer {
+                Ok(answer) => println!("  {}: {}", name, answer),
+                Err(error) => println!("  {}: FAIL {}", name, error),
+            }
+        }
+        let path = differential::Disagreement::default_path(day, disagreement.seed);
+        match disagreement.save(&path) {
+            Ok(()) => println!("  input written to {}", path.display()),
+            Err(error) => eprintln!("{}", error),
+        }
+    }
+    if failures > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
@@ -0,0 +1,62 @@
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::TcpListener,
+    sync::{Arc, Mutex},
+    thread,
+};
+
+/// A request the mock server received.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MockRequest {
+    pub request_line: String,
+    pub cookie: String,
+    pub user_agent: String,
+    pub body: String,
+}
+
+pub type Requests = Arc<Mutex<Vec<MockRequest>>>;
+
+/// Stand-in for the Advent of Code server, answering every request with the given status and body.
+/// Returns its base url and the requests it has received so far.
+pub fn mock_server(status: u16, body: &'static str) -> (String, Requests) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let recorded = Arc::clone(&requests);
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+            let mut cookie = String::new();
+            let mut user_agent = String::new();
+            let mut content_length = 0;
+            loop {
+                let mut header = String::new();
+                reader.read_line(&mut header).unwrap();
+                let header = header.trim_end();
+                if header.is_empty() {
+                    break;
+                }
+                let Some((name, value)) = header.split_once(": ") else { continue };
+                match name.to_ascii_lowercase().as_str() {
+                    "cookie" => cookie = value.to_string(),
+                    "user-agent" => user_agent = value.to_string(),
+                    "content-length" => content_length = value.parse().unwrap(),
+                    _ => {}
+                }
+            }
+            let mut request_body = vec![0; content_length];
+            reader.read_exact(&mut request_body).unwrap();
+            recorded.lock().unwrap().push(MockRequest {
+                request_line: request_line.trim_end().to_string(),
+                cookie,
+                user_agent,
+                body: String::from_utf8(request_body).unwrap(),
+            });
+            write!(stream, "HTTP/1.1 {} Mock\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", status, body.len(), body).unwrap();
+        }
+    });
+    (base_url, requests)
+}
@@ -0,0 +1,86 @@
+use std::fmt::Write;
+
+use aoc_common::AllocationStats;
+use clap::ValueEnum;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    Text,
+    Json,
+    Csv,
+}
+
+/// One solved part, as reported to dashboards in JSON or CSV.
+#[derive(Debug, Clone, Serialize)]
+pub struct RunRecord {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub elapsed_ns: u64,
+    pub input: String,
+    /// SHA-256 of the input after line endings were normalized.
+    pub input_sha256: String,
+    /// None if there is no expected answer for this input.
+    pub matches_expected: Option<bool>,
+    /// None unless the runner was built with the `count-allocations` feature.
+    #[serde(flatten)]
+    pub allocations: Option<AllocationStats>,
+    /// The parse phase the part was solved after, shared by the parts of a single run.
+    pub parse_elapsed_ns: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_allocations: Option<AllocationStats>,
+}
+
+pub fn sha256_hex(input_string: &str) -> String {
+    Sha256::digest(input_string.as_bytes())
+        .iter()
+        .fold(String::new(), |mut hex, byte| {
+            let _ = write!(hex, "{:02x}", byte);
+            hex
+        })
+}
+
+/// Print records as JSON or CSV. Text output is printed by each command itself as it goes.
+pub fn print_records(format: OutputFormat, records: &[RunRecord]) {
+    match format {
+        OutputFormat::Text => {}
+        OutputFormat::Json => {
+            println!("{}", serde_json::to_string_pretty(records).expect("records serialize to JSON"));
+        }
+        OutputFormat::Csv => {
+            println!(
+                "day,part,answer,elapsed_ns,input,input_sha256,matches_expected,allocations,bytes_allocated,peak_bytes,\
+                 parse_elapsed_ns,parse_allocations,parse_bytes_allocated,parse_peak_bytes"
+            );
+            for record in records {
+                println!(
+                    "{},{},{},{},{},{},{},{},{},{},{},{},{},{}",
+                    record.day,
+                    record.part,
+                    csv_field(&record.answer),
+                    record.elapsed_ns,
+                    csv_field(&record.input),
+                    record.input_sha256,
+                    record.matches_expected.map_or(String::new(), |matches| matches.to_string()),
+                    record.allocations.map_or(String::new(), |stats| stats.allocations.to_string()),
+                    record.allocations.map_or(String::new(), |stats| stats.bytes_allocated.to_string()),
+                    record.allocations.map_or(String::new(), |stats| stats.peak_bytes.to_string()),
+                    record.parse_elapsed_ns,
+                    record.parse_allocations.map_or(String::new(), |stats| stats.allocations.to_string()),
+                    record.parse_allocations.map_or(String::new(), |stats| stats.bytes_allocated.to_string()),
+                    record.parse_allocations.map_or(String::new(), |stats| stats.peak_bytes.to_string()),
+                );
+            }
+        }
+    }
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
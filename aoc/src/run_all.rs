@@ -0,0 +1,128 @@
+use std::{panic, process::ExitCode, time::Instant};
+
+use aoc_common::{allocation, DayRun, ExpectedAnswers, Part, Verdict};
+use rayon::prelude::*;
+
+use crate::{
+    bench::{format_bytes, format_duration},
+    days::{self, Day},
+    output::{self, OutputFormat, RunRecord},
+};
+
+/// Everything one day produced, collected on a worker thread and printed once all days are done.
+pub struct DayOutcome {
+    pub day: u8,
+    pub input: String,
+    pub input_sha256: String,
+    /// Every part is solved on its own, so its run holds the parse it was solved after and the part.
+    pub parts: Vec<(Part, Result<DayRun, String>)>,
+}
+
+/// Solve the given parts of one day in parallel, catching panics and errors per part.
+pub fn run_day(solution: &Day, parts: &[Part]) -> DayOutcome {
+    let input_source = solution.default_input();
+    let input_string = match input_source.read() {
+        Ok(input_string) => input_string,
+        Err(error) => {
+            return DayOutcome {
+                day: solution.day,
+                input: input_source.to_string(),
+                input_sha256: String::new(),
+                parts: parts.iter().map(|&part| (part, Err(error.to_string()))).collect(),
+            };
+        }
+    };
+    let parts = parts.par_iter()
+        .map(|&part| {
+            // a panicking solution must not take the other days down with it
+            let result = match panic::catch_unwind(|| solution.run(&input_string, &[part])) {
+                Ok(Ok(day_run)) => Ok(day_run),
+                Ok(Err(error)) => Err(error.with_input_file(input_source.display_path()).to_string()),
+                Err(_) => Err("solution panicked".to_string()),
+            };
+            (part, result)
+        })
+        .collect();
+    DayOutcome { day: solution.day, input: input_source.to_string(), input_sha256: output::sha256_hex(&input_string), parts }
+}
+
+/// Solve the given parts of every day on the rayon thread pool, then report them all at once.
+pub fn run_all(parts: &[Part], format: OutputFormat) -> ExitCode {
+    let expected_answers = ExpectedAnswers::load(ExpectedAnswers::default_path()).unwrap_or_else(|error| {
+        eprintln!("{}", error);
+        ExpectedAnswers::default()
+    });
+    let started = Instant::now();
+    let outcomes = days::all().par_iter().map(|solution| run_day(solution, parts)).collect::<Vec<_>>();
+    let wall_time = started.elapsed();
+
+    let mut failures = vec![];
+    let mut records = vec![];
+    let mut total_ns = 0;
+    let counting = allocation::is_counting();
+    if format == OutputFormat::Text {
+        print!("{:>3} {:>4}  {:<20} {:<8} {:>10}", "Day", "Part", "Answer", "Status", "Time");
+        if counting {
+            print!(" {:>12} {:>12} {:>12}", "Allocations", "Allocated", "Peak");
+        }
+        println!();
+    }
+    for outcome in &outcomes {
+        for (part, result) in &outcome.parts {
+            let (day_run, part_run) = match result {
+                Ok(day_run) => (day_run, &day_run.parts[0]),
+                Err(error) => {
+                    if format == OutputFormat::Text {
+                        println!("{:>3} {:>4}  {:<20} {:<8} {:>10}", outcome.day, part.number(), "", "ERROR", "");
+                    }
+                    failures.push(format!("Day {} Part {}: {}", outcome.day, part, error));
+                    continue;
+                }
+            };
+            let verdict = expected_answers.verify(outcome.day, *part, "input.txt", &part_run.answer);
+            if let Verdict::Fail { expected } = &verdict {
+                failures.push(format!("Day {} Part {}: expected {}, got {}", outcome.day, part, expected, part_run.answer));
+            }
+            let elapsed_ns = part_run.elapsed.as_nanos() as u64;
+            total_ns += elapsed_ns;
+            if format == OutputFormat::Text {
+                let answer = part_run.answer.to_string();
+                print!("{:>3} {:>4}  {:<20} {:<8} {:>10}", outcome.day, part.number(), answer, verdict.to_string(), format_duration(elapsed_ns));
+                if let Some(stats) = &part_run.allocations {
+                    print!(" {:>12} {:>12} {:>12}", stats.allocations, format_bytes(stats.bytes_allocated), format_bytes(stats.peak_bytes));
+                }
+                println!();
+            }
+            records.push(RunRecord {
+                day: outcome.day,
+                part: part.number(),
+                answer: part_run.answer.to_string(),
+                elapsed_ns,
+                input: outcome.input.clone(),
+                input_sha256: outcome.input_sha256.clone(),
+                matches_expected: verdict.matches(),
+                allocations: part_run.allocations,
+                parse_elapsed_ns: day_run.parse_elapsed.as_nanos() as u64,
+                parse_allocations: day_run.parse_allocations,
+            });
+        }
+    }
+
+    if format == OutputFormat::Text {
+        println!("{} parts solved in {} total, {} wall time", records.len(), format_duration(total_ns), format_duration(wall_time.as_nanos() as u64));
+    } else {
+        output::print_records(format, &records);
+    }
+    if failures.is_empty() {
+        return ExitCode::SUCCESS;
+    }
+    for failure in &failures {
+        // stdout only carries the records in the machine-readable formats
+        if format == OutputFormat::Text {
+            println!("{}", failure);
+        } else {
+            eprintln!("{}", failure);
+        }
+    }
+    ExitCode::FAILURE
+}
@@ -0,0 +1,141 @@
+use std::{fs, path::{Path, PathBuf}};
+
+/// Files of a new day crate, relative to the crate directory, with their templates.
+const TEMPLATES: [(&str, &str); 7] = [
+    ("Cargo.toml", include_str!("../templates/day/Cargo.toml.tmpl")),
+    ("src/lib.rs", include_str!("../templates/day/lib.rs.tmpl")),
+    ("src/main.rs", include_str!("../templates/day/main.rs.tmpl")),
+    ("src/input_example.txt", include_str!("../templates/day/input_example.txt.tmpl")),
+    // empty until `aoc fetch` installs the real input, but it must exist for the embedded-input feature
+    ("src/input.txt", ""),
+    ("tests/examples.rs", include_str!("../templates/day/examples.rs.tmpl")),
+    ("benches/day_{{DAY_PADDED}}.rs", include_str!("../templates/day/bench.rs.tmpl")),
+];
+
+fn render(template: &str, day: u8) -> String {
+    template
+        .replace("{{DAY_PADDED}}", &format!("{:02}", day))
+        .replace("{{DAY}}", &day.to_string())
+}
+
+/// Insert `line` among the lines starting with `prefix`, keeping them in day order.
+fn insert_sorted(contents: &str, prefix: &str, line: &str) -> Result<String, String> {
+    let mut lines = contents.lines().collect::<Vec<_>>();
+    let matching = lines.iter().enumerate().filter(|(_, existing)| existing.starts_with(prefix)).collect::<Vec<_>>();
+    let Some((first, _)) = matching.first() else {
+        return Err(format!("no lines starting with {:?} to register {:?} next to", prefix.trim(), line.trim()));
+    };
+    if matching.iter().any(|(_, existing)| **existing == line) {
+        return Err(format!("{:?} is already registered", line.trim()));
+    }
+    let index = matching.iter()
+        .filter(|(_, existing)| **existing < line)
+        .map(|(index, _)| index + 1)
+        .next_back()
+        .unwrap_or(*first);
+    lines.insert(index, line);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Create the crate for a new day under the workspace root and register it in the workspace and the runner.
+/// Nothing is written unless every step can be done, and an existing day is never overwritten.
+pub fn new_day(root: &Path, day: u8) -> Result<Vec<PathBuf>, String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("Day {} is not an Advent of Code day", day));
+    }
+    let crate_name = format!("day_{:02}", day);
+    let crate_dir = root.join(&crate_name);
+    if crate_dir.exists() {
+        return Err(format!("{} already exists, refusing to overwrite it", crate_dir.display()));
+    }
+
+    let registrations: [(PathBuf, &[(&str, String)]); 3] = [
+        (root.join("Cargo.toml"), &[
+            ("    \"day_", format!("    \"{}\",", crate_name)),
+            ("day_", format!("{} = {{ path = \"{}\" }}", crate_name, crate_name)),
+        ]),
+        (root.join("aoc").join("Cargo.toml"), &[
+            ("    \"day_", format!("    \"{}/embedded-input\",", crate_name)),
+            ("day_", format!("{}.workspace = true", crate_name)),
+        ]),
+        (root.join("aoc").join("src").join("days.rs"), &[
+            ("        Day::of::<day_", format!("        Day::of::<{}::Day{:02}>(),", crate_name, day)),
+        ]),
+    ];
+    let mut updated_files = Vec::new();
+    for (path, insertions) in registrations {
+        let mut contents = fs::read_to_string(&path).map_err(|error| format!("Failed to read {} - error: {}", path.display(), error))?;
+        for (prefix, line) in insertions {
+            contents = insert_sorted(&contents, prefix, line).map_err(|error| format!("Failed to update {} - {}", path.display(), error))?;
+        }
+        updated_files.push((path, contents));
+    }
+
+    let mut written = Vec::new();
+    for (relative_path, template) in TEMPLATES {
+        let path = crate_dir.join(render(relative_path, day));
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|error| format!("Failed to create {} - error: {}", parent.display(), error))?;
+        }
+        fs::write(&path, render(template, day)).map_err(|error| format!("Failed to write {} - error: {}", path.display(), error))?;
+        written.push(path);
+    }
+    for (path, contents) in updated_files {
+        fs::write(&path, contents).map_err(|error| format!("Failed to write {} - error: {}", path.display(), error))?;
+        written.push(path);
+    }
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, process};
+
+    use aoc_common::workspace_root;
+
+    use super::*;
+
+    /// A copy of the files the generator edits, so the real workspace is left alone.
+    fn scratch_workspace(name: &str) -> PathBuf {
+        let root = env::temp_dir().join(format!("aoc-scaffold-{}-{}", name, process::id()));
+        let _ = fs::remove_dir_all(&root);
+        for file in ["Cargo.toml", "aoc/Cargo.toml", "aoc/src/days.rs"] {
+            let path = root.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::copy(workspace_root().join(file), path).unwrap();
+        }
+        root
+    }
+
+    #[test]
+    fn inserts_in_day_order() {
+        let contents = "[workspace]\n    \"day_01\",\n    \"day_03\",\n]\n";
+        assert_eq!(
+            insert_sorted(contents, "    \"day_", "    \"day_02\",").unwrap(),
+            "[workspace]\n    \"day_01\",\n    \"day_02\",\n    \"day_03\",\n]\n"
+        );
+        assert!(insert_sorted(contents, "    \"day_", "    \"day_03\",").is_err());
+    }
+
+    #[test]
+    fn creates_and_registers_a_day_once() {
+        let root = scratch_workspace("new");
+
+        let written = new_day(&root, 24).unwrap();
+        assert!(written.contains(&root.join("day_24").join("benches").join("day_24.rs")));
+        let lib = fs::read_to_string(root.join("day_24").join("src").join("lib.rs")).unwrap();
+        assert!(lib.contains("pub struct Day24;") && lib.contains("const DAY: u8 = 24;"));
+
+        let workspace_manifest = fs::read_to_string(root.join("Cargo.toml")).unwrap();
+        assert!(workspace_manifest.contains("    \"day_24\",\n]"));
+        assert!(workspace_manifest.contains("day_24 = { path = \"day_24\" }\n"));
+        let runner_manifest = fs::read_to_string(root.join("aoc").join("Cargo.toml")).unwrap();
+        assert!(runner_manifest.contains("    \"day_24/embedded-input\",\n]"));
+        assert!(runner_manifest.contains("day_24.workspace = true\n"));
+        let days = fs::read_to_string(root.join("aoc").join("src").join("days.rs")).unwrap();
+        assert!(days.contains("        Day::of::<day_24::Day24>(),\n    ]"));
+
+        assert!(new_day(&root, 24).unwrap_err().contains("refusing to overwrite"));
+        assert!(new_day(&root, 26).is_err());
+    }
+}
@@ -0,0 +1,278 @@
+use std::{
+    fs::{self, OpenOptions},
+    io::Write,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{config::Config, fetch::{request_error, USER_AGENT}};
+
+/// How long the server makes you wait after a wrong answer when it does not say otherwise.
+const DEFAULT_COOLDOWN_SECS: u64 = 60;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// Submitted during the cooldown, the answer was not checked.
+    Wait,
+    /// The part was already solved, the answer was not checked.
+    AlreadySolved,
+}
+
+impl Outcome {
+    fn is_wrong(self) -> bool {
+        matches!(self, Outcome::TooHigh | Outcome::TooLow | Outcome::Wrong)
+    }
+
+    pub fn describe(self) -> &'static str {
+        match self {
+            Outcome::Correct => "correct",
+            Outcome::TooHigh => "too high",
+            Outcome::TooLow => "too low",
+            Outcome::Wrong => "wrong",
+            Outcome::Wait => "submitted too soon",
+            Outcome::AlreadySolved => "already solved",
+        }
+    }
+}
+
+/// One line of the submission log.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+    /// Seconds the server asked to wait before the next submission.
+    pub wait_secs: u64,
+}
+
+/// Every answer submitted so far, stored as JSON lines next to the cached inputs.
+#[derive(Debug)]
+pub struct SubmissionLog {
+    path: PathBuf,
+    submissions: Vec<Submission>,
+}
+
+impl SubmissionLog {
+    pub fn default_path(config: &Config) -> PathBuf {
+        config.cache_dir.join(config.year.to_string()).join("submissions.jsonl")
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let mut submissions = Vec::new();
+        if path.exists() {
+            let contents = fs::read_to_string(path).map_err(|error| format!("Failed to read {} - error: {}", path.display(), error))?;
+            for (index, line) in contents.lines().enumerate().filter(|(_, line)| !line.trim().is_empty()) {
+                let submission = serde_json::from_str(line)
+                    .map_err(|error| format!("Invalid submission log {} line {} - error: {}", path.display(), index + 1, error))?;
+                submissions.push(submission);
+            }
+        }
+        Ok(Self { path: path.to_path_buf(), submissions })
+    }
+
+    pub fn append(&mut self, submission: Submission) -> Result<(), String> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent).map_err(|error| format!("Failed to create {} - error: {}", parent.display(), error))?;
+        }
+        let line = serde_json::to_string(&submission).map_err(|error| error.to_string())?;
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .and_then(|mut file| writeln!(file, "{}", line))
+            .map_err(|error| format!("Failed to write {} - error: {}", self.path.display(), error))?;
+        self.submissions.push(submission);
+        Ok(())
+    }
+
+    /// Why the answer must not be submitted, judging by earlier submissions, or None if it may be.
+    pub fn refusal(&self, day: u8, part: u8, answer: &str, now: u64) -> Option<String> {
+        // the cooldown is account wide, not per puzzle
+        if let Some(last) = self.submissions.iter().max_by_key(|submission| submission.timestamp) {
+            let allowed_at = last.timestamp + last.wait_secs;
+            if now < allowed_at {
+                return Some(format!("cooling down, wait {}s before submitting again", allowed_at - now));
+            }
+        }
+
+        let earlier = self.submissions.iter().filter(|submission| submission.day == day && submission.part == part);
+        let value = answer.parse::<i128>().ok();
+        for submission in earlier {
+            if submission.outcome == Outcome::Correct {
+                return Some(format!("already solved with {}", submission.answer));
+            }
+            if !submission.outcome.is_wrong() {
+                continue;
+            }
+            if submission.answer == answer {
+                return Some(format!("{} was already rejected as {}", answer, submission.outcome.describe()));
+            }
+            let (Some(value), Ok(bound)) = (value, submission.answer.parse::<i128>()) else { continue };
+            match submission.outcome {
+                Outcome::TooHigh if value >= bound => return Some(format!("{} is not below {}, which is too high", answer, bound)),
+                Outcome::TooLow if value <= bound => return Some(format!("{} is not above {}, which is too low", answer, bound)),
+                _ => {}
+            }
+        }
+        None
+    }
+}
+
+/// Read the verdict and the requested wait out of the server's HTML response.
+pub fn parse_response(html: &str) -> Result<(Outcome, u64), String> {
+    let text = html.to_lowercase();
+    if text.contains("that's the right answer") {
+        Ok((Outcome::Correct, 0))
+    } else if text.contains("you gave an answer too recently") {
+        Ok((Outcome::Wait, parse_time_left(&text).unwrap_or(DEFAULT_COOLDOWN_SECS)))
+    } else if text.contains("that's not the right answer") {
+        let outcome = if text.contains("your answer is too high") {
+            Outcome::TooHigh
+        } else if text.contains("your answer is too low") {
+            Outcome::TooLow
+        } else {
+            Outcome::Wrong
+        };
+        Ok((outcome, parse_wait_before_retry(&text).unwrap_or(DEFAULT_COOLDOWN_SECS)))
+    } else if text.contains("don't seem to be solving the right level") {
+        Ok((Outcome::AlreadySolved, 0))
+    } else {
+        Err("Unrecognized response from the server".to_string())
+    }
+}
+
+/// "you have 1m 5s left to wait" as seconds.
+fn parse_time_left(text: &str) -> Option<u64> {
+    // the message says "you have to wait" first, so search backwards from the end of the sentence
+    let end = text.find(" left to wait")?;
+    let start = text[..end].rfind("you have ")? + "you have ".len();
+    text[start..end].split_whitespace().try_fold(0, |total, amount| {
+        let (number, unit) = amount.split_at(amount.find(|c: char| !c.is_ascii_digit())?);
+        let number = number.parse::<u64>().ok()?;
+        match unit {
+            "h" => Some(total + number * 3600),
+            "m" => Some(total + number * 60),
+            "s" => Some(total + number),
+            _ => None,
+        }
+    })
+}
+
+/// "please wait one minute" or "please wait 5 minutes" as seconds.
+fn parse_wait_before_retry(text: &str) -> Option<u64> {
+    let start = text.find("please wait ")? + "please wait ".len();
+    let amount = text[start..].split_whitespace().next()?;
+    let minutes = match amount {
+        "one" => 1,
+        amount => amount.parse::<u64>().ok()?,
+    };
+    Some(minutes * 60)
+}
+
+pub fn unix_now() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |elapsed| elapsed.as_secs())
+}
+
+/// Submit an answer unless the log already rules it out, and log the server's verdict.
+pub fn submit_answer(config: &Config, log: &mut SubmissionLog, day: u8, part: u8, answer: &str, now: u64) -> Result<Submission, String> {
+    if let Some(refusal) = log.refusal(day, part, answer, now) {
+        return Err(format!("Refusing to submit: {}", refusal));
+    }
+    let session = config.session()?;
+    let url = format!("{}/{}/day/{}/answer", config.base_url.trim_end_matches('/'), config.year, day);
+    let response = ureq::post(&url)
+        .set("Cookie", &format!("session={}", session))
+        .set("User-Agent", USER_AGENT)
+        .send_form(&[("level", &part.to_string()), ("answer", answer)])
+        .map_err(|error| request_error("submit to", &url, error))?;
+    let html = response.into_string().map_err(|error| format!("Failed to submit to {} - error: {}", url, error))?;
+    let (outcome, wait_secs) = parse_response(&html)?;
+
+    let submission = Submission { timestamp: now, day, part, answer: answer.to_string(), outcome, wait_secs };
+    log.append(submission.clone())?;
+    Ok(submission)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, process};
+
+    use super::*;
+    use crate::mock_server::mock_server;
+
+    const TOO_HIGH: &str = "<article><p>That's not the right answer; your answer is too high. \
+        Please wait one minute before trying again.</p></article>";
+    const CORRECT: &str = "<article><p>That's the right answer! You are one gold star closer.</p></article>";
+
+    fn test_setup(name: &str, base_url: String) -> (Config, SubmissionLog) {
+        let cache_dir = env::temp_dir().join(format!("aoc-submit-{}-{}", name, process::id()));
+        let _ = fs::remove_dir_all(&cache_dir);
+        let config = Config { session: Some("secret".to_string()), base_url, year: 2023, cache_dir };
+        let log = SubmissionLog::load(&SubmissionLog::default_path(&config)).unwrap();
+        (config, log)
+    }
+
+    #[test]
+    fn parses_server_responses() {
+        assert_eq!(parse_response(CORRECT), Ok((Outcome::Correct, 0)));
+        assert_eq!(parse_response(TOO_HIGH), Ok((Outcome::TooHigh, 60)));
+        assert_eq!(
+            parse_response("That's not the right answer; your answer is too low. Please wait 5 minutes before trying again."),
+            Ok((Outcome::TooLow, 300))
+        );
+        assert_eq!(
+            parse_response("You gave an answer too recently; you have to wait after submitting an answer before trying again. You have 1m 5s left to wait."),
+            Ok((Outcome::Wait, 65))
+        );
+        assert_eq!(
+            parse_response("You don't seem to be solving the right level. Did you already complete it?"),
+            Ok((Outcome::AlreadySolved, 0))
+        );
+        assert!(parse_response("<html>maintenance</html>").is_err());
+    }
+
+    #[test]
+    fn logs_submissions_and_refuses_known_wrong_answers() {
+        let (base_url, requests) = mock_server(200, TOO_HIGH);
+        let (config, mut log) = test_setup("wrong", base_url);
+
+        let submission = submit_answer(&config, &mut log, 5, 2, "100", 1000).unwrap();
+        assert_eq!(submission.outcome, Outcome::TooHigh);
+        assert_eq!(requests.lock().unwrap()[0].request_line, "POST /2023/day/5/answer HTTP/1.1");
+        assert_eq!(requests.lock().unwrap()[0].body, "level=2&answer=100");
+        assert_eq!(requests.lock().unwrap()[0].user_agent, USER_AGENT);
+
+        // still cooling down
+        assert!(submit_answer(&config, &mut log, 5, 2, "50", 1030).unwrap_err().contains("wait 30s"));
+        // known too high, or above something too high
+        assert!(submit_answer(&config, &mut log, 5, 2, "100", 2000).unwrap_err().contains("already rejected"));
+        assert!(submit_answer(&config, &mut log, 5, 2, "150", 2000).unwrap_err().contains("not below 100"));
+        assert_eq!(requests.lock().unwrap().len(), 1);
+
+        // the log survives a reload
+        let reloaded = SubmissionLog::load(&SubmissionLog::default_path(&config)).unwrap();
+        assert_eq!(reloaded.submissions, vec![submission]);
+    }
+
+    #[test]
+    fn refuses_parts_already_solved() {
+        let (base_url, requests) = mock_server(200, CORRECT);
+        let (config, mut log) = test_setup("solved", base_url);
+
+        assert_eq!(submit_answer(&config, &mut log, 1, 1, "142", 1000).unwrap().outcome, Outcome::Correct);
+        assert!(submit_answer(&config, &mut log, 1, 1, "143", 2000).unwrap_err().contains("already solved with 142"));
+        // other parts are unaffected
+        assert_eq!(submit_answer(&config, &mut log, 1, 2, "281", 2000).unwrap().outcome, Outcome::Correct);
+        assert_eq!(requests.lock().unwrap().len(), 2);
+    }
+}
@@ -0,0 +1,140 @@
+//! Counting of heap allocations, for seeing how much memory each phase of a day uses.
+//!
+//! Nothing is counted unless a binary installs [`CountingAllocator`] as its global allocator.
+//! The counters are kept per thread, so phases solved side by side on a thread pool do not
+//! count each other's allocations.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+use serde::{Deserialize, Serialize};
+
+/// The system allocator, counting every allocation made through it.
+pub struct CountingAllocator;
+
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    static ALLOCATIONS: Cell<u64> = const { Cell::new(0) };
+    static BYTES_ALLOCATED: Cell<u64> = const { Cell::new(0) };
+    // signed, as memory allocated on another thread may be freed on this one
+    static LIVE_BYTES: Cell<i64> = const { Cell::new(0) };
+    static PEAK_LIVE_BYTES: Cell<i64> = const { Cell::new(0) };
+}
+
+impl CountingAllocator {
+    fn record_allocation(size: usize) {
+        if !INSTALLED.load(Ordering::Relaxed) {
+            INSTALLED.store(true, Ordering::Relaxed);
+        }
+        // the counters are gone while the thread is torn down, those allocations are not counted
+        let _ = ALLOCATIONS.try_with(|allocations| allocations.set(allocations.get() + 1));
+        let _ = BYTES_ALLOCATED.try_with(|bytes| bytes.set(bytes.get() + size as u64));
+        Self::record_live_bytes(size as i64);
+    }
+
+    fn record_live_bytes(change: i64) {
+        let _ = LIVE_BYTES.try_with(|live| {
+            live.set(live.get() + change);
+            let _ = PEAK_LIVE_BYTES.try_with(|peak| peak.set(peak.get().max(live.get())));
+        });
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        Self::record_allocation(layout.size());
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        Self::record_allocation(layout.size());
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        // a reallocation counts as allocating the new size and then freeing the old one
+        Self::record_allocation(new_size);
+        Self::record_live_bytes(-(layout.size() as i64));
+        System.realloc(ptr, layout, new_size)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        Self::record_live_bytes(-(layout.size() as i64));
+        System.dealloc(ptr, layout)
+    }
+}
+
+/// Heap usage of one phase.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AllocationStats {
+    pub allocations: u64,
+    pub bytes_allocated: u64,
+    /// Most heap memory in use at once, on top of what was in use when the phase started.
+    pub peak_bytes: u64,
+}
+
+/// Whether a binary installed [`CountingAllocator`], so that [`measure`] has anything to report.
+pub fn is_counting() -> bool {
+    INSTALLED.load(Ordering::Relaxed)
+}
+
+/// Run `f` and count the allocations it makes on the current thread,
+/// or None when allocations are not counted.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<AllocationStats>) {
+    if !is_counting() {
+        return (f(), None);
+    }
+    let allocations = ALLOCATIONS.with(Cell::get);
+    let bytes_allocated = BYTES_ALLOCATED.with(Cell::get);
+    let live_bytes = LIVE_BYTES.with(Cell::get);
+    PEAK_LIVE_BYTES.with(|peak| peak.set(live_bytes));
+
+    let result = f();
+    let stats = AllocationStats {
+        allocations: ALLOCATIONS.with(Cell::get) - allocations,
+        bytes_allocated: BYTES_ALLOCATED.with(Cell::get) - bytes_allocated,
+        peak_bytes: (PEAK_LIVE_BYTES.with(Cell::get) - live_bytes).max(0) as u64,
+    };
+    (result, Some(stats))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    #[test]
+    fn counts_the_allocations_of_a_phase() {
+        let kept = vec![0u8; 100];
+        let (_, stats) = measure(|| {
+            let first = vec![0u8; 1000];
+            drop(first);
+            let second = vec![0u8; 600];
+            second.len()
+        });
+        let stats = stats.unwrap();
+        assert_eq!(stats.allocations, 2);
+        assert_eq!(stats.bytes_allocated, 1600);
+        // the first vector was freed before the second one was allocated, and the kept one predates the phase
+        assert_eq!(stats.peak_bytes, 1000);
+        drop(kept);
+    }
+
+    #[test]
+    fn counts_a_reallocation_as_a_new_allocation() {
+        let (_, stats) = measure(|| {
+            let mut numbers = Vec::<u64>::with_capacity(4);
+            numbers.extend(0..8);
+            numbers
+        });
+        let stats = stats.unwrap();
+        assert_eq!(stats.allocations, 2);
+        assert!(stats.peak_bytes >= 64 && stats.peak_bytes <= stats.bytes_allocated);
+    }
+}
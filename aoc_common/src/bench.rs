@@ -0,0 +1,21 @@
+//! Criterion benchmarks shared by the `benches` of each day, enabled with the `bench` feature.
+
+use std::hint::black_box;
+
+use criterion::Criterion;
+
+use crate::{input_file_path, read_input_file, Solution};
+
+/// Benchmark the parse, part1 and part2 phases of a day on its puzzle input.
+pub fn bench_solution<S: Solution>(c: &mut Criterion) {
+    let input_string = read_input_file(input_file_path(S::DAY, "input.txt")).unwrap_or_else(|error| panic!("{}", error));
+    let input = S::parse(&input_string).unwrap_or_else(|error| panic!("{}", error));
+
+    let mut group = c.benchmark_group(format!("day_{:02}", S::DAY));
+    // brute-force parts like day_05 part 2 take seconds per iteration, keep the sample count at criterion's minimum
+    group.sample_size(10);
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(&input_string))));
+    group.bench_function("part1", |b| b.iter(|| S::part1(black_box(&input))));
+    group.bench_function("part2", |b| b.iter(|| S::part2(black_box(&input))));
+    group.finish();
+}
@@ -0,0 +1,52 @@
+use std::{env, ffi::OsString, process::ExitCode};
+
+use tracing::info_span;
+
+use crate::{init_tracing, run, AocError, InputSource, Part, Solution};
+
+/// Shared `main` of the per-day binaries: read the input, solve both parts the way the runner
+/// does and report errors as diagnostics.
+///
+/// The input is taken from the first argument that is not a `-v` ("-" for stdin, or a file path),
+/// falling back to the embedded input or the day's input.txt.
+/// Diagnostics go to stderr, shown by `-v` (repeated for more detail) or filtered by the `AOC_LOG`
+/// environment variable.
+pub fn day_main<S: Solution>() -> ExitCode {
+    let mut verbosity = 0u8;
+    let mut input_arg = None;
+    for arg in env::args_os().skip(1) {
+        match arg.to_str() {
+            Some("--verbose") => verbosity = verbosity.saturating_add(1),
+            Some(flags) if flags.len() > 1 && flags.starts_with('-') && flags[1..].bytes().all(|flag| flag == b'v') => {
+                verbosity = verbosity.saturating_add((flags.len() - 1) as u8);
+            }
+            _ => input_arg = input_arg.or(Some(arg)),
+        }
+    }
+    day_main_with_input::<S>(input_arg, verbosity, |input_string| {
+        for part_run in run::<S>(input_string, &Part::ALL)?.parts {
+            println!("Part {}: {}", part_run.part, part_run.answer);
+        }
+        Ok(())
+    })
+}
+
+/// [`day_main`] for binaries that parse their own arguments, with the input argument and verbosity they were given.
+pub fn day_main_with_input<S: Solution>(
+    input_arg: Option<OsString>,
+    verbosity: u8,
+    solve: impl FnOnce(&str) -> Result<(), AocError>,
+) -> ExitCode {
+    init_tracing(verbosity, None);
+    let _span = info_span!("day", day = S::DAY).entered();
+    let input_source = input_arg
+        .map(InputSource::from_arg)
+        .unwrap_or_else(|| InputSource::default_for_day(S::DAY, S::EMBEDDED_INPUT));
+    match input_source.read().and_then(|input_string| solve(&input_string)) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("{}", error.with_input_file(input_source.display_path()));
+            ExitCode::FAILURE
+        }
+    }
+}
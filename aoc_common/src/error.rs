@@ -0,0 +1,46 @@
+use std::{error::Error, fmt, io, path::{Path, PathBuf}};
+
+use crate::parse::ParseError;
+
+#[derive(Debug)]
+pub enum AocError {
+    Io { path: PathBuf, source: io::Error },
+    Answers { path: PathBuf, message: String },
+    Parse(ParseError),
+}
+
+impl AocError {
+    /// Attach the input file a parse error occurred in, so the diagnostic can name it.
+    pub fn with_input_file(self, path: impl AsRef<Path>) -> Self {
+        match self {
+            AocError::Parse(error) => AocError::Parse(error.with_file(path)),
+            error => error,
+        }
+    }
+}
+
+impl From<ParseError> for AocError {
+    fn from(error: ParseError) -> Self {
+        AocError::Parse(error)
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::Io { path, source } => write!(f, "Failed to read {} - error: {}", path.display(), source),
+            AocError::Answers { path, message } => write!(f, "Invalid expected answers in {} - error: {}", path.display(), message),
+            AocError::Parse(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl Error for AocError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AocError::Io { source, .. } => Some(source),
+            AocError::Parse(error) => Some(error),
+            _ => None,
+        }
+    }
+}
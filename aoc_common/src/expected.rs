@@ -0,0 +1,81 @@
+use std::{collections::HashMap, fmt, fs, path::{Path, PathBuf}};
+
+use serde::Deserialize;
+
+use crate::{input::workspace_root, AocError, Answer, Part};
+
+#[derive(Debug, Clone, Default, Deserialize)]
+struct PartAnswers {
+    part1: Option<String>,
+    part2: Option<String>,
+}
+
+/// Registry of known answers, loaded from `answers.toml` in the workspace root.
+#[derive(Debug, Clone, Default)]
+pub struct ExpectedAnswers {
+    answers: HashMap<String, HashMap<String, PartAnswers>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+}
+
+impl Verdict {
+    /// Whether the answer matched, or None if there was nothing to compare it with.
+    pub fn matches(&self) -> Option<bool> {
+        match self {
+            Verdict::Pass => Some(true),
+            Verdict::Fail { .. } => Some(false),
+            Verdict::Unknown => None,
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "PASS"),
+            Verdict::Fail { .. } => write!(f, "FAIL"),
+            Verdict::Unknown => write!(f, "UNKNOWN"),
+        }
+    }
+}
+
+impl ExpectedAnswers {
+    pub fn default_path() -> PathBuf {
+        workspace_root().join("answers.toml")
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, AocError> {
+        let path = path.as_ref();
+        let contents = fs::read_to_string(path)
+            .map_err(|source| AocError::Io { path: path.to_path_buf(), source })?;
+        Self::parse(&contents)
+            .map_err(|message| AocError::Answers { path: path.to_path_buf(), message })
+    }
+
+    pub fn parse(contents: &str) -> Result<Self, String> {
+        let answers = toml::from_str(contents).map_err(|error| error.to_string())?;
+        Ok(Self { answers })
+    }
+
+    /// The expected answer for a day's part when solved with the given input file, e.g. "input.txt".
+    pub fn get(&self, day: u8, part: Part, input_file_name: &str) -> Option<&str> {
+        let part_answers = self.answers.get(&format!("day_{:02}", day))?.get(input_file_name)?;
+        match part {
+            Part::One => part_answers.part1.as_deref(),
+            Part::Two => part_answers.part2.as_deref(),
+        }
+    }
+
+    pub fn verify(&self, day: u8, part: Part, input_file_name: &str, answer: &Answer) -> Verdict {
+        match self.get(day, part, input_file_name) {
+            Some(expected) if expected == answer.to_string() => Verdict::Pass,
+            Some(expected) => Verdict::Fail { expected: expected.to_string() },
+            None => Verdict::Unknown,
+        }
+    }
+}
@@ -0,0 +1,192 @@
+//! Signed 2D geometry: points, compass directions and bounding boxes.
+//!
+//! `x` is the column and grows to the east, `y` is the row and grows to the south, matching
+//! how puzzle inputs are read top to bottom. Use [`Point::from_row_column`] and
+//! [`Point::row_column`] to convert from and to [`crate::Grid`] positions.
+
+use std::{fmt, ops::{Add, AddAssign, Mul, Sub}};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point::new(0, 0);
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    pub fn from_row_column((row, column): (usize, usize)) -> Self {
+        Self::new(column as i64, row as i64)
+    }
+
+    /// The grid position of the point, or None if it lies left of or above the grid.
+    pub fn row_column(self) -> Option<(usize, usize)> {
+        Some((usize::try_from(self.y).ok()?, usize::try_from(self.x).ok()?))
+    }
+
+    pub fn manhattan_distance(self, other: Point) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// Distance when diagonal steps count as one, like a king moves in chess.
+    pub fn chebyshev_distance(self, other: Point) -> u64 {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    pub fn step(self, direction: Direction) -> Point {
+        self + direction.offset()
+    }
+
+    pub fn neighbors4(self) -> [Point; 4] {
+        Direction::ALL.map(|direction| self.step(direction))
+    }
+
+    /// The direction of `other` if it is one step north, east, south or west of this point.
+    pub fn direction_to(self, other: Point) -> Option<Direction> {
+        Direction::ALL.into_iter().find(|&direction| self.step(direction) == other)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Point;
+
+    fn mul(self, factor: i64) -> Point {
+        Point::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// Clockwise from north.
+    pub const ALL: [Direction; 4] = [Direction::North, Direction::East, Direction::South, Direction::West];
+
+    /// One step in this direction, north being towards the first row.
+    pub fn offset(self) -> Point {
+        match self {
+            Direction::North => Point::new(0, -1),
+            Direction::East => Point::new(1, 0),
+            Direction::South => Point::new(0, 1),
+            Direction::West => Point::new(-1, 0),
+        }
+    }
+
+    pub fn turn_right(self) -> Direction {
+        match self {
+            Direction::North => Direction::East,
+            Direction::East => Direction::South,
+            Direction::South => Direction::West,
+            Direction::West => Direction::North,
+        }
+    }
+
+    pub fn turn_left(self) -> Direction {
+        self.turn_right().opposite()
+    }
+
+    pub fn opposite(self) -> Direction {
+        self.turn_right().turn_right()
+    }
+}
+
+/// The smallest rectangle containing a set of points, with both corners inclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BoundingBox {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl BoundingBox {
+    /// None if there are no points.
+    pub fn from_points(points: impl IntoIterator<Item = Point>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(points.fold(Self { min: first, max: first }, |bounds, point| bounds.including(point)))
+    }
+
+    /// The box grown just enough to contain `point`.
+    pub fn including(self, point: Point) -> Self {
+        Self {
+            min: Point::new(self.min.x.min(point.x), self.min.y.min(point.y)),
+            max: Point::new(self.max.x.max(point.x), self.max.y.max(point.y)),
+        }
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x) && (self.min.y..=self.max.y).contains(&point.y)
+    }
+
+    pub fn width(&self) -> u64 {
+        self.min.x.abs_diff(self.max.x) + 1
+    }
+
+    pub fn height(&self) -> u64 {
+        self.min.y.abs_diff(self.max.y) + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn directions_turn_and_step() {
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction::West.turn_right(), Direction::North);
+        assert_eq!(Direction::East.opposite(), Direction::West);
+        let point = Point::new(2, 0);
+        assert_eq!(point.step(Direction::North), Point::new(2, -1));
+        assert_eq!(point.step(Direction::North).row_column(), None);
+        assert_eq!(point.direction_to(Point::new(1, 0)), Some(Direction::West));
+        assert_eq!(point.direction_to(Point::new(1, 1)), None);
+    }
+
+    #[test]
+    fn measures_distances_and_bounds() {
+        let (a, b) = (Point::new(1, 6), Point::new(5, 11));
+        assert_eq!(a.manhattan_distance(b), 9);
+        assert_eq!(a.chebyshev_distance(b), 5);
+        let bounds = BoundingBox::from_points([a, b, Point::new(-2, 8)]).unwrap();
+        assert_eq!((bounds.min, bounds.max), (Point::new(-2, 6), Point::new(5, 11)));
+        assert_eq!((bounds.width(), bounds.height()), (8, 6));
+        assert!(bounds.contains(Point::new(0, 7)) && !bounds.contains(Point::new(0, 12)));
+    }
+}
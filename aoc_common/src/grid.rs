@@ -0,0 +1,219 @@
+//! A rectangular grid of cells, addressed by `(row, column)` positions.
+//!
+//! Neighbor iterators only yield positions inside the grid, so cells on the edges need no
+//! special casing and nothing ever computes `0 - 1` on a `usize`.
+
+use std::{fmt, ops::{Index, IndexMut}};
+
+use crate::{BoundingBox, ParseError, Point};
+
+/// Offsets of the 4 orthogonal neighbors: north, east, south, west.
+const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+/// Offsets of all 8 neighbors, clockwise from north.
+const ALL_AROUND: [(isize, isize); 8] = [(-1, 0), (-1, 1), (0, 1), (1, 1), (1, 0), (1, -1), (0, -1), (-1, -1)];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    /// Cells in row-major order.
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Grid of `width` columns from cells in row-major order. Panics if they do not fill whole rows.
+    pub fn new(width: usize, cells: Vec<T>) -> Self {
+        assert!(width > 0 && cells.len().is_multiple_of(width), "{} cells do not fill rows of {}", cells.len(), width);
+        Self { width, height: cells.len() / width, cells }
+    }
+
+    /// Parse one cell per character, with `expected` describing the valid characters in errors.
+    /// Rows must all have the same width and there must be at least one.
+    pub fn parse(input_string: &str, mut parse_cell: impl FnMut(char) -> Option<T>, expected: &str) -> Result<Self, ParseError> {
+        let width = input_string.lines().next().map_or(0, |line| line.chars().count());
+        if width == 0 {
+            return Err(ParseError::at(input_string, input_string.lines().next().unwrap_or_default(), "row of the grid"));
+        }
+        let mut cells = Vec::with_capacity(input_string.len());
+        for line in input_string.lines() {
+            if line.chars().count() != width {
+                return Err(ParseError::at(input_string, line, format!("row of {} cells", width)));
+            }
+            for (i, c) in line.char_indices() {
+                let cell = parse_cell(c).ok_or_else(|| ParseError::at(input_string, &line[i..i + c.len_utf8()], expected))?;
+                cells.push(cell);
+            }
+        }
+        Ok(Self::new(width, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (row, column): (usize, usize)) -> Option<&T> {
+        (row < self.height && column < self.width).then(|| &self.cells[row * self.width + column])
+    }
+
+    pub fn get_mut(&mut self, (row, column): (usize, usize)) -> Option<&mut T> {
+        (row < self.height && column < self.width).then(|| &mut self.cells[row * self.width + column])
+    }
+
+    /// The cell at `point`, whose x is the column and y the row.
+    pub fn get_point(&self, point: Point) -> Option<&T> {
+        self.get(point.row_column()?)
+    }
+
+    pub fn get_point_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.get_mut(point.row_column()?)
+    }
+
+    /// The points covered by the grid.
+    pub fn bounds(&self) -> BoundingBox {
+        BoundingBox { min: Point::ORIGIN, max: Point::new(self.width as i64 - 1, self.height as i64 - 1) }
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| (i / width, i % width))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    fn offset(&self, (row, column): (usize, usize), (d_row, d_column): (isize, isize)) -> Option<(usize, usize)> {
+        let row = row.checked_add_signed(d_row).filter(|&row| row < self.height)?;
+        let column = column.checked_add_signed(d_column).filter(|&column| column < self.width)?;
+        Some((row, column))
+    }
+
+    /// Positions of the orthogonal neighbors inside the grid, clockwise from north.
+    pub fn neighbors4(&self, position: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        ORTHOGONAL.iter().filter_map(move |&offset| self.offset(position, offset))
+    }
+
+    /// Positions of the orthogonal and diagonal neighbors inside the grid, clockwise from north.
+    pub fn neighbors8(&self, position: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        ALL_AROUND.iter().filter_map(move |&offset| self.offset(position, offset))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    pub fn column(&self, column: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(column).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|column| self.column(column))
+    }
+
+    /// Positions of every cell matching `predicate`, row by row.
+    pub fn find_all<'a>(&'a self, mut predicate: impl FnMut(&T) -> bool + 'a) -> impl Iterator<Item = (usize, usize)> + 'a {
+        self.iter().filter(move |(_, cell)| predicate(cell)).map(|(position, _)| position)
+    }
+
+    /// A grid of the same shape, with every cell converted by `f`.
+    pub fn map<U>(&self, mut f: impl FnMut((usize, usize), &T) -> U) -> Grid<U> {
+        Grid { width: self.width, height: self.height, cells: self.iter().map(|(position, cell)| f(position, cell)).collect() }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Rows become columns and columns become rows.
+    pub fn transpose(&self) -> Self {
+        let cells = self.columns().flatten().cloned().collect();
+        Self { width: self.height, height: self.width, cells }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: (usize, usize)) -> &T {
+        self.get(position).unwrap_or_else(|| panic!("{:?} is outside of a {}x{} grid", position, self.height, self.width))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, position: (usize, usize)) -> &mut T {
+        let (height, width) = (self.height, self.width);
+        self.get_mut(position).unwrap_or_else(|| panic!("{:?} is outside of a {}x{} grid", position, height, width))
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get_point(point).unwrap_or_else(|| panic!("{} is outside of a {}x{} grid", point, self.height, self.width))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        let (height, width) = (self.height, self.width);
+        self.get_point_mut(point).unwrap_or_else(|| panic!("{} is outside of a {}x{} grid", point, height, width))
+    }
+}
+
+/// One line per row, with the cells written next to each other.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chars(input_string: &str) -> Grid<char> {
+        Grid::parse(input_string, Some, "any character").unwrap()
+    }
+
+    #[test]
+    fn neighbors_stay_inside_the_grid() {
+        let grid = chars("abc\ndef");
+        assert_eq!(grid.neighbors4((0, 0)).collect::<Vec<_>>(), [(0, 1), (1, 0)]);
+        assert_eq!(grid.neighbors8((0, 0)).collect::<Vec<_>>(), [(0, 1), (1, 1), (1, 0)]);
+        assert_eq!(grid.neighbors8((1, 2)).collect::<Vec<_>>(), [(0, 2), (1, 1), (0, 1)]);
+        assert_eq!(grid.get((2, 0)), None);
+    }
+
+    #[test]
+    fn transposes_and_displays() {
+        let grid = chars("abc\ndef");
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(grid.find_all(|&c| c > 'c').collect::<Vec<_>>(), [(1, 0), (1, 1), (1, 2)]);
+    }
+
+    #[test]
+    fn rejects_ragged_rows_and_unknown_cells() {
+        let error = Grid::parse("ab\nabc", Some, "any character").unwrap_err();
+        assert_eq!((error.line, error.expected.as_str()), (2, "row of 2 cells"));
+        let error = Grid::parse("..\n.x", |c| (c == '.').then_some(c), "\".\"").unwrap_err();
+        assert_eq!((error.line, error.column, error.found.as_str()), (2, 2, "x"));
+    }
+}
@@ -0,0 +1,106 @@
+use std::{fmt, fs, io::{self, Read}, path::{Path, PathBuf}};
+
+use tracing::debug;
+
+use crate::AocError;
+
+/// Read a puzzle input file and normalize its line endings, see [`normalize_input`].
+pub fn read_input_file(input_file_path: impl AsRef<Path>) -> Result<String, AocError> {
+    let input_path = input_file_path.as_ref();
+    fs::read_to_string(input_path)
+        .map(|input_string| normalize_input(&input_string))
+        .map_err(|source| AocError::Io { path: input_path.to_path_buf(), source })
+}
+
+/// Convert CRLF line endings to LF and strip trailing newlines,
+/// so that parsers splitting on "\n\n" or counting lines see the same input on every platform.
+pub fn normalize_input(input_string: &str) -> String {
+    input_string
+        .replace("\r\n", "\n")
+        .trim_end_matches('\n')
+        .to_string()
+}
+
+/// Where a puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+    /// A copy of a day's input.txt compiled into the binary with the `embedded-input` feature.
+    Embedded { day: u8, input_string: &'static str },
+}
+
+impl InputSource {
+    /// Interpret a command line argument: "-" is stdin, anything else a file path.
+    pub fn from_arg(arg: impl AsRef<Path>) -> Self {
+        let arg = arg.as_ref();
+        if arg == Path::new("-") {
+            InputSource::Stdin
+        } else {
+            InputSource::File(arg.to_path_buf())
+        }
+    }
+
+    /// The input a day runs on when none is given: the embedded copy if the binary was built with one,
+    /// otherwise the day's input.txt in the workspace.
+    pub fn default_for_day(day: u8, embedded_input: Option<&'static str>) -> Self {
+        match embedded_input {
+            Some(input_string) => InputSource::Embedded { day, input_string },
+            None => InputSource::File(input_file_path(day, "input.txt")),
+        }
+    }
+
+    pub fn read(&self) -> Result<String, AocError> {
+        let input_string = match self {
+            InputSource::Stdin => {
+                let mut input_string = String::new();
+                io::stdin()
+                    .read_to_string(&mut input_string)
+                    .map_err(|source| AocError::Io { path: self.display_path(), source })?;
+                normalize_input(&input_string)
+            }
+            InputSource::File(path) => read_input_file(path)?,
+            InputSource::Embedded { input_string, .. } => normalize_input(input_string),
+        };
+        debug!(source = %self, bytes = input_string.len(), "input read");
+        Ok(input_string)
+    }
+
+    /// Name used for this source in diagnostics.
+    pub fn display_path(&self) -> PathBuf {
+        PathBuf::from(self.to_string())
+    }
+
+    /// File name to look up expected answers for, None when the input did not come from a known file.
+    pub fn file_name(&self) -> Option<String> {
+        match self {
+            InputSource::Stdin => None,
+            InputSource::File(path) => path.file_name().map(|name| name.to_string_lossy().into_owned()),
+            InputSource::Embedded { .. } => Some("input.txt".to_string()),
+        }
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::Stdin => write!(f, "<stdin>"),
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Embedded { day, .. } => write!(f, "day_{:02}/src/input.txt (embedded)", day),
+        }
+    }
+}
+
+/// Path of an input file inside a day's crate, e.g. `day_05/src/input.txt`.
+/// Resolved against the workspace root, so binaries work from any working directory.
+pub fn input_file_path(day: u8, file_name: &str) -> PathBuf {
+    workspace_root()
+        .join(format!("day_{:02}", day))
+        .join("src")
+        .join(file_name)
+}
+
+pub fn workspace_root() -> PathBuf {
+    // the parent rather than "..", so paths printed in reports stay clean
+    Path::new(env!("CARGO_MANIFEST_DIR")).parent().expect("aoc_common lives inside the workspace").to_path_buf()
+}
@@ -0,0 +1,24 @@
+pub mod allocation;
+#[cfg(feature = "bench")]
+pub mod bench;
+mod day_main;
+mod error;
+mod expected;
+pub mod geometry;
+pub mod grid;
+mod input;
+mod logging;
+pub mod parse;
+mod solution;
+pub mod testing;
+
+pub use allocation::{AllocationStats, CountingAllocator};
+pub use day_main::{day_main, day_main_with_input};
+pub use error::AocError;
+pub use expected::{ExpectedAnswers, Verdict};
+pub use geometry::{BoundingBox, Direction, Point};
+pub use grid::Grid;
+pub use input::{input_file_path, normalize_input, read_input_file, workspace_root, InputSource};
+pub use logging::{init_tracing, LOG_FILTER_ENV};
+pub use parse::ParseError;
+pub use solution::{run, solve, Answer, DayRun, Part, PartRun, Solution};
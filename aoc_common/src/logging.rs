@@ -0,0 +1,34 @@
+//! Diagnostics through `tracing`, written to stderr or as JSON lines to a file.
+//!
+//! The solutions only emit spans and events; which of them are shown is decided once here,
+//! by the binary's verbosity or by a filter in the `AOC_LOG` environment variable.
+
+use std::{env, fs::File, io::{self, IsTerminal}, sync::Mutex};
+
+use tracing_subscriber::{fmt::format::FmtSpan, EnvFilter};
+
+/// Environment variable with a filter such as `debug` or `day_05=trace,info`, taking precedence over the verbosity.
+pub const LOG_FILTER_ENV: &str = "AOC_LOG";
+
+/// Install the global subscriber. Verbosity 0 shows warnings only, every step up shows
+/// info, debug and trace as well. Spans are reported when they close, with their run time.
+pub fn init_tracing(verbosity: u8, json_log: Option<File>) {
+    let level = match verbosity {
+        0 => "warn",
+        1 => "info",
+        2 => "debug",
+        _ => "trace",
+    };
+    let filter = match env::var(LOG_FILTER_ENV) {
+        Ok(directives) => EnvFilter::try_new(&directives).unwrap_or_else(|error| {
+            eprintln!("Ignoring invalid {} filter {:?} - error: {}", LOG_FILTER_ENV, directives, error);
+            EnvFilter::new(level)
+        }),
+        Err(_) => EnvFilter::new(level),
+    };
+    let subscriber = tracing_subscriber::fmt().with_env_filter(filter).with_span_events(FmtSpan::CLOSE);
+    match json_log {
+        Some(file) => subscriber.json().with_writer(Mutex::new(file)).init(),
+        None => subscriber.with_writer(io::stderr).with_ansi(io::stderr().is_terminal()).init(),
+    }
+}
@@ -0,0 +1,141 @@
+//! Parse errors with compiler-style diagnostics, plus small helpers the day parsers share.
+//!
+//! Positions are derived from subslices: every helper takes the `context` string being parsed
+//! and a `token` that must be a slice of it, so no parser has to track offsets by hand.
+
+use std::{fmt, path::{Path, PathBuf}, str::FromStr};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Input file the error occurred in, attached by whoever read the file.
+    pub file: Option<PathBuf>,
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    pub expected: String,
+    /// The offending token, empty when the line or input ended early.
+    pub found: String,
+    /// Full text of the offending line, rendered below the error message.
+    pub source_line: String,
+}
+
+impl ParseError {
+    /// Error for `token`, which must be a subslice of `context`.
+    /// Tokens outside of `context` are reported at its end instead of panicking.
+    pub fn at(context: &str, token: &str, expected: impl Into<String>) -> Self {
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(context.as_ptr() as usize)
+            .filter(|&offset| offset + token.len() <= context.len() && context.is_char_boundary(offset))
+            .unwrap_or(context.len());
+        let line_start = context[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = context[offset..].find('\n').map_or(context.len(), |i| offset + i);
+        Self {
+            file: None,
+            line: context[..offset].matches('\n').count() + 1,
+            column: context[line_start..offset].chars().count() + 1,
+            expected: expected.into(),
+            found: token.lines().next().unwrap_or_default().to_string(),
+            source_line: context[line_start..line_end].to_string(),
+        }
+    }
+
+    /// Error at the end of `context`, for input that stopped before an expected token.
+    pub fn at_end(context: &str, expected: impl Into<String>) -> Self {
+        Self::at(context, &context[context.len()..], expected)
+    }
+
+    /// Move the error down by `lines`, for errors found in a single line of a larger input.
+    pub fn with_line_offset(mut self, lines: usize) -> Self {
+        self.line += lines;
+        self
+    }
+
+    pub fn with_file(mut self, file: impl AsRef<Path>) -> Self {
+        self.file = Some(file.as_ref().to_path_buf());
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.found.is_empty() {
+            writeln!(f, "error: expected {}", self.expected)?;
+        } else {
+            writeln!(f, "error: expected {}, found {:?}", self.expected, self.found)?;
+        }
+        let file = self.file.as_ref().map_or("<input>".to_string(), |file| file.display().to_string());
+        let gutter = " ".repeat(self.line.to_string().len());
+        writeln!(f, "{}--> {}:{}:{}", gutter, file, self.line, self.column)?;
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", self.line, self.source_line)?;
+        write!(
+            f,
+            "{} | {}{}",
+            gutter,
+            " ".repeat(self.column - 1),
+            "^".repeat(self.found.chars().count().max(1))
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// The first whitespace-delimited word of `text`, used as the "found" token of an error.
+pub fn first_word(text: &str) -> &str {
+    let text = text.trim_start();
+    &text[..text.find(char::is_whitespace).unwrap_or(text.len())]
+}
+
+pub fn parse_number<T: FromStr>(context: &str, token: &str) -> Result<T, ParseError> {
+    token.parse().map_err(|_| ParseError::at(context, token, "number"))
+}
+
+/// Parse whitespace-separated numbers, e.g. " 41 48 83 86 17".
+pub fn parse_numbers<T: FromStr>(context: &str, text: &str) -> Result<Vec<T>, ParseError> {
+    text.split_whitespace().map(|token| parse_number(context, token)).collect()
+}
+
+pub fn strip_prefix<'a>(context: &str, text: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+    text.strip_prefix(prefix).ok_or_else(|| ParseError::at(context, first_word(text), format!("{:?}", prefix)))
+}
+
+pub fn split_once<'a>(context: &str, text: &'a str, delimiter: &str) -> Result<(&'a str, &'a str), ParseError> {
+    text.split_once(delimiter).ok_or_else(|| ParseError::at(context, &text[text.len()..], format!("{:?}", delimiter)))
+}
+
+/// Parse every line of the input with a parser that only sees a single line,
+/// shifting its errors to the line's position in the whole input.
+pub fn parse_lines<'a, T>(
+    input_string: &'a str,
+    mut parse_line: impl FnMut(&'a str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    input_string.lines()
+        .enumerate()
+        .map(|(index, line)| parse_line(line).map_err(|error| error.with_line_offset(index)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locates_token_and_renders_caret() {
+        let input = "seeds: 79 14\n\nseed-to-soil map:\n50 98 x2";
+        let token = &input[input.len() - 2..];
+        let error = ParseError::at(input, token, "number").with_file("day_05/src/input.txt");
+        assert_eq!((error.line, error.column), (4, 7));
+        assert_eq!(
+            error.to_string(),
+            "error: expected number, found \"x2\"\n --> day_05/src/input.txt:4:7\n  |\n4 | 50 98 x2\n  |       ^^"
+        );
+    }
+
+    #[test]
+    fn reports_end_of_input() {
+        let error = ParseError::at_end("Time: 7 15", "\"Distance:\"");
+        assert_eq!((error.line, error.column), (1, 11));
+        assert!(error.to_string().starts_with("error: expected \"Distance:\"\n"));
+    }
+}
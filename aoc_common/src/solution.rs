@@ -0,0 +1,180 @@
+use std::{fmt, str::FromStr, time::{Duration, Instant}};
+
+use tracing::{debug, info_span};
+
+use crate::{allocation::{self, AllocationStats}, AocError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("invalid part '{}', expected 1 or 2", s)),
+        }
+    }
+}
+
+/// The answer to one part of a puzzle.
+/// Days return anything from `u32` over `usize` to `i32`, so integers are widened to 128 bits.
+#[derive(Debug, Clone, Eq)]
+pub enum Answer {
+    Signed(i128),
+    Unsigned(u128),
+    Text(String),
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Answer::Signed(a), Answer::Signed(b)) => a == b,
+            (Answer::Unsigned(a), Answer::Unsigned(b)) => a == b,
+            (Answer::Signed(a), Answer::Unsigned(b)) | (Answer::Unsigned(b), Answer::Signed(a)) => {
+                u128::try_from(*a).is_ok_and(|a| a == *b)
+            }
+            (Answer::Text(a), Answer::Text(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Signed(value) => write!(f, "{}", value),
+            Answer::Unsigned(value) => write!(f, "{}", value),
+            Answer::Text(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+macro_rules! impl_answer_from {
+    ($variant:ident => $($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(value: $t) -> Self {
+                Answer::$variant(value as _)
+            }
+        })*
+    };
+}
+
+impl_answer_from!(Signed => i8, i16, i32, i64, i128, isize);
+impl_answer_from!(Unsigned => u8, u16, u32, u64, u128, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+/// A day's puzzle: parse the input once, then solve both parts on the parsed input.
+pub trait Solution {
+    const DAY: u8;
+
+    /// The day's input.txt, when compiled in with the day crate's `embedded-input` feature.
+    const EMBEDDED_INPUT: Option<&'static str> = None;
+
+    /// Parsed puzzle input, allowed to borrow from the input string.
+    type Input<'a>;
+
+    fn parse(input_string: &str) -> Result<Self::Input<'_>, AocError>;
+
+    /// Fails on an input that parsed but that the part cannot be solved for,
+    /// e.g. a line only the other part can read.
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, AocError>;
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, AocError>;
+}
+
+/// Parse the input and solve a single part of the given solution.
+pub fn solve<S: Solution>(input_string: &str, part: Part) -> Result<Answer, AocError> {
+    let input = parse::<S>(input_string)?;
+    solve_part::<S>(&input, part)
+}
+
+fn parse<S: Solution>(input_string: &str) -> Result<S::Input<'_>, AocError> {
+    let _span = info_span!("parse", day = S::DAY, bytes = input_string.len()).entered();
+    S::parse(input_string)
+}
+
+fn solve_part<S: Solution>(input: &S::Input<'_>, part: Part) -> Result<Answer, AocError> {
+    let _span = info_span!("part", day = S::DAY, part = part.number()).entered();
+    let answer = match part {
+        Part::One => S::part1(input)?,
+        Part::Two => S::part2(input)?,
+    };
+    debug!(%answer, "solved");
+    Ok(answer)
+}
+
+/// Answer and run time of one part.
+#[derive(Debug, Clone)]
+pub struct PartRun {
+    pub part: Part,
+    pub answer: Answer,
+    pub elapsed: Duration,
+    /// None unless the binary counts allocations.
+    pub allocations: Option<AllocationStats>,
+}
+
+/// Run times of every phase of a day: parsing once, then each requested part on the parsed input.
+#[derive(Debug, Clone)]
+pub struct DayRun {
+    pub parse_elapsed: Duration,
+    pub parse_allocations: Option<AllocationStats>,
+    pub parts: Vec<PartRun>,
+}
+
+impl DayRun {
+    pub fn part(&self, part: Part) -> Option<&PartRun> {
+        self.parts.iter().find(|part_run| part_run.part == part)
+    }
+}
+
+/// Parse the input once and solve the given parts, timing each phase separately
+/// and counting its allocations when the binary does.
+pub fn run<S: Solution>(input_string: &str, parts: &[Part]) -> Result<DayRun, AocError> {
+    let start = Instant::now();
+    let (input, parse_allocations) = allocation::measure(|| parse::<S>(input_string));
+    let input = input?;
+    let parse_elapsed = start.elapsed();
+
+    let parts = parts.iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let (answer, allocations) = allocation::measure(|| solve_part::<S>(&input, part));
+            Ok(PartRun { part, answer: answer?, elapsed: start.elapsed(), allocations })
+        })
+        .collect::<Result<_, AocError>>()?;
+    Ok(DayRun { parse_elapsed, parse_allocations, parts })
+}
@@ -0,0 +1,17 @@
+//! Helpers for the example tests in each day's `tests` directory.
+
+use crate::{input_file_path, read_input_file, solve, Answer, Part, Solution};
+
+/// Solve one part of a day on one of the example files shipped next to its input,
+/// e.g. "input_example.txt". Panics on any error since it is meant for tests.
+pub fn solve_example<S: Solution>(file_name: &str, part: Part) -> Answer {
+    let input_path = input_file_path(S::DAY, file_name);
+    let input_string = read_input_file(&input_path).unwrap_or_else(|error| panic!("{}", error));
+    solve::<S>(&input_string, part).unwrap_or_else(|error| panic!("{}", error))
+}
+
+/// Assert that a part solved on an example file yields the answer given in the puzzle text.
+pub fn assert_example<S: Solution>(file_name: &str, part: Part, expected: impl Into<Answer>) {
+    let answer = solve_example::<S>(file_name, part);
+    assert_eq!(answer, expected.into(), "day {} part {} on {}", S::DAY, part, file_name);
+}
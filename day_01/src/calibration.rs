@@ -0,0 +1,359 @@
+use std::{fmt, str::FromStr};
+
+use aoc_common::{ParseError, Part};
+
+use crate::{scanner::{Scanner, Token}, vocabulary::Vocabulary};
+
+/// How the digits of a line become its calibration value.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Rule {
+    /// The first and the last digit as a two-digit number, as in the puzzle.
+    #[default]
+    FirstAndLast,
+    /// Every digit added up.
+    SumOfDigits,
+    /// The largest number written with consecutive numeric digits.
+    LargestNumber,
+    /// The first and the last spelled-out digit as a two-digit number, ignoring numeric digits.
+    FirstAndLastWords,
+    /// The digit nearest to a 1-based column, the leftmost one of two as near.
+    NearestToColumn(usize),
+}
+
+impl Rule {
+    /// Whether the rule only looks at spelled-out digits, which part 1 does not read.
+    pub fn reads_words_only(self) -> bool {
+        self == Rule::FirstAndLastWords
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Rule::FirstAndLast => write!(f, "first-and-last"),
+            Rule::SumOfDigits => write!(f, "sum"),
+            Rule::LargestNumber => write!(f, "largest-number"),
+            Rule::FirstAndLastWords => write!(f, "first-and-last-words"),
+            Rule::NearestToColumn(column) => write!(f, "nearest:{}", column),
+        }
+    }
+}
+
+impl FromStr for Rule {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "first-and-last" => Ok(Rule::FirstAndLast),
+            "sum" => Ok(Rule::SumOfDigits),
+            "largest-number" => Ok(Rule::LargestNumber),
+            "first-and-last-words" => Ok(Rule::FirstAndLastWords),
+            _ => match s.strip_prefix("nearest:").and_then(|column| column.parse().ok()) {
+                Some(column) if column > 0 => Ok(Rule::NearestToColumn(column)),
+                _ => Err(format!(
+                    "invalid rule '{}', expected first-and-last, sum, largest-number, first-and-last-words or nearest:<column>",
+                    s
+                )),
+            },
+        }
+    }
+}
+
+/// What to do with a line that holds no digit at all.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MissingDigits {
+    /// Stop with an error pointing at the line.
+    #[default]
+    Error,
+    /// Leave the line out of the total.
+    Skip,
+    /// Count the line with a calibration value of 0.
+    Zero,
+}
+
+impl fmt::Display for MissingDigits {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MissingDigits::Error => write!(f, "error"),
+            MissingDigits::Skip => write!(f, "skip"),
+            MissingDigits::Zero => write!(f, "zero"),
+        }
+    }
+}
+
+impl FromStr for MissingDigits {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "error" => Ok(MissingDigits::Error),
+            "skip" => Ok(MissingDigits::Skip),
+            "zero" => Ok(MissingDigits::Zero),
+            _ => Err(format!("invalid policy '{}', expected error, skip or zero", s)),
+        }
+    }
+}
+
+/// One line of a calibration document with every digit found on it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineCalibration<'a> {
+    /// 1-based line number.
+    pub number: usize,
+    pub line: &'a str,
+    pub tokens: Vec<Token>,
+    /// The tokens the value was made from, in order of the line.
+    pub chosen: Vec<Token>,
+    /// None for a skipped line.
+    pub value: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Calibration<'a> {
+    pub lines: Vec<LineCalibration<'a>>,
+    pub total: u64,
+    pub rule: Rule,
+    pub missing_digits: MissingDigits,
+    /// Numbers of the lines without a digit the rule could use, which were skipped or counted as 0.
+    pub lines_without_digit: Vec<usize>,
+}
+
+/// How calibration values are read from a document: which words count as digits, how the
+/// digits of a line become its value and what happens to a line without any.
+pub struct CalibrationPolicy {
+    scanner: Scanner,
+    pub rule: Rule,
+    pub missing_digits: MissingDigits,
+}
+
+impl CalibrationPolicy {
+    pub fn new(vocabulary: &Vocabulary, rule: Rule, missing_digits: MissingDigits) -> Self {
+        Self { scanner: Scanner::new(vocabulary), rule, missing_digits }
+    }
+
+    /// The policy of a part with another rule: part 1 only reads numeric digits, part 2 the words
+    /// of `vocabulary` as well. Rules that only look at words are rejected for part 1.
+    pub fn for_part(part: Part, vocabulary: &Vocabulary, rule: Rule, missing_digits: MissingDigits) -> Result<Self, String> {
+        match part {
+            Part::One if rule.reads_words_only() => Err(format!("rule {} needs spelled-out digits, which part 1 does not read", rule)),
+            Part::One => Ok(Self::new(&Vocabulary::numerals_only(), rule, missing_digits)),
+            Part::Two => Ok(Self::new(vocabulary, rule, missing_digits)),
+        }
+    }
+
+    /// The first and the last numeric digit of every line.
+    pub fn part1() -> Self {
+        Self::new(&Vocabulary::numerals_only(), Rule::FirstAndLast, MissingDigits::Error)
+    }
+
+    /// The first and the last digit of every line, numeric or a word of `vocabulary`.
+    pub fn part2(vocabulary: &Vocabulary) -> Self {
+        Self::new(vocabulary, Rule::FirstAndLast, MissingDigits::Error)
+    }
+
+    /// Read the calibration value of every line and sum them up, failing on a line without a digit
+    /// if the policy says so and on a total beyond 64 bits.
+    pub fn calibrate<'a>(&self, input_string: &'a str) -> Result<Calibration<'a>, ParseError> {
+        let mut lines = vec![];
+        let mut lines_without_digit = vec![];
+        let mut total = 0u64;
+        for (index, line) in input_string.lines().enumerate() {
+            let tokens = self.scanner.scan(line);
+            let (chosen, value) = match self.value(input_string, line, &tokens)? {
+                Some((chosen, value)) => (chosen, Some(value)),
+                None => {
+                    lines_without_digit.push(index + 1);
+                    match self.missing_digits {
+                        MissingDigits::Error => return Err(ParseError::at(input_string, line, "a digit")),
+                        MissingDigits::Skip => (vec![], None),
+                        MissingDigits::Zero => (vec![], Some(0)),
+                    }
+                }
+            };
+            if let Some(value) = value {
+                total = total.checked_add(value)
+                    .ok_or_else(|| ParseError::at(input_string, line, "a calibration value that keeps the total within 64 bits"))?;
+            }
+            lines.push(LineCalibration { number: index + 1, line, tokens, chosen, value });
+        }
+        Ok(Calibration { lines, total, rule: self.rule, missing_digits: self.missing_digits, lines_without_digit })
+    }
+
+    /// The tokens the rule picks from a line and the value they make, None if it finds none.
+    fn value(&self, input_string: &str, line: &str, tokens: &[Token]) -> Result<Option<(Vec<Token>, u64)>, ParseError> {
+        let two_digits = |first: &Token, last: &Token| (vec![*first, *last], u64::from(first.digit * 10 + last.digit));
+        Ok(match self.rule {
+            Rule::FirstAndLast => tokens.first().zip(tokens.last()).map(|(first, last)| two_digits(first, last)),
+            Rule::SumOfDigits => {
+                (!tokens.is_empty()).then(|| (tokens.to_vec(), tokens.iter().map(|token| u64::from(token.digit)).sum()))
+            }
+            Rule::LargestNumber => {
+                let mut largest: Option<(Vec<Token>, u64)> = None;
+                for number in numbers(tokens) {
+                    let text = &line[number[0].start..number[number.len() - 1].end];
+                    let value = text.parse::<u64>().map_err(|_| ParseError::at(input_string, text, "a number of at most 64 bits"))?;
+                    if largest.as_ref().is_none_or(|(_, largest)| value > *largest) {
+                        largest = Some((number, value));
+                    }
+                }
+                largest
+            }
+            Rule::FirstAndLastWords => {
+                let mut words = tokens.iter().filter(|token| token.word);
+                words.next().map(|first| two_digits(first, words.next_back().unwrap_or(first)))
+            }
+            Rule::NearestToColumn(column) => tokens.iter()
+                .min_by_key(|token| {
+                    let start = line[..token.start].chars().count() + 1;
+                    let end = start + line[token.start..token.end].chars().count();
+                    start.saturating_sub(column) + (column + 1).saturating_sub(end)
+                })
+                .map(|token| (vec![*token], u64::from(token.digit))),
+        })
+    }
+}
+
+/// Runs of numeric digits that directly follow each other.
+fn numbers(tokens: &[Token]) -> Vec<Vec<Token>> {
+    let mut numbers: Vec<Vec<Token>> = vec![];
+    for token in tokens.iter().filter(|token| !token.word) {
+        match numbers.last_mut() {
+            Some(number) if number[number.len() - 1].end == token.start => number.push(*token),
+            _ => numbers.push(vec![*token]),
+        }
+    }
+    numbers
+}
+
+/// Every line with its digits marked below it, the digits the rule chose and the resulting
+/// value, followed by a summary. Overlapping digits are marked on separate rows.
+pub fn render_report(calibration: &Calibration) -> String {
+    let gutter = calibration.lines.last().map_or(1, |line| line.number.to_string().len());
+    let mut report = String::new();
+    for line in &calibration.lines {
+        report.push_str(&format!("{:>gutter$} | {}\n", line.number, line.line));
+        for row in marker_rows(line) {
+            report.push_str(&format!("{:>gutter$} | {}\n", "", row.trim_end()));
+        }
+        let digits = line.chosen.iter().map(|token| token.digit.to_string()).collect::<Vec<_>>();
+        let outcome = match (calibration.rule, line.value) {
+            (_, None) => "no digit, skipped".to_string(),
+            (_, Some(value)) if line.chosen.is_empty() => format!("no digit, value {}", value),
+            (Rule::FirstAndLast | Rule::FirstAndLastWords, Some(value)) => format!("first {}, last {}, value {}", digits[0], digits[1], value),
+            (Rule::SumOfDigits, Some(value)) => format!("sum of {}, value {}", digits.join(" + "), value),
+            (Rule::LargestNumber, Some(value)) => format!("largest number, value {}", value),
+            (Rule::NearestToColumn(column), Some(value)) => format!("nearest to column {}, value {}", column, value),
+        };
+        for token in &line.tokens {
+            if let Some(fuzzy) = &token.fuzzy {
+                let confidence = fuzzy.confidence() * 100.0;
+                report.push_str(&format!("{:>gutter$} = fuzzy {:?} read as {}, {:.0}% confidence\n", "", &line.line[token.start..token.end], token.digit, confidence));
+            }
+        }
+        report.push_str(&format!("{:>gutter$} = {}\n", "", outcome));
+    }
+    report.push_str(&format!("{} lines, total {}\n", calibration.lines.len(), calibration.total));
+    if !calibration.lines_without_digit.is_empty() {
+        let numbers = calibration.lines_without_digit.iter().map(|number| number.to_string()).collect::<Vec<_>>();
+        report.push_str(&format!("Lines without a digit ({}): {}\n", calibration.missing_digits, numbers.join(", ")));
+    }
+    report
+}
+
+/// Rows of markers under the line, every token spelled out as its digit over its width,
+/// with a token that overlaps one already placed moved to the next row.
+fn marker_rows(line: &LineCalibration) -> Vec<String> {
+    // end column of the last marker of each row, in characters
+    let mut rows: Vec<(String, usize)> = vec![];
+    for token in &line.tokens {
+        let start = line.line[..token.start].chars().count();
+        let width = line.line[token.start..token.end].chars().count();
+        let row = match rows.iter().position(|&(_, end)| end <= start) {
+            Some(row) => row,
+            None => {
+                rows.push((String::new(), 0));
+                rows.len() - 1
+            }
+        };
+        let (markers, end) = &mut rows[row];
+        markers.push_str(&" ".repeat(start - *end));
+        markers.push_str(&token.digit.to_string().repeat(width));
+        *end = start + width;
+    }
+    rows.into_iter().map(|(markers, _)| markers).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{input_file_path, read_input_file};
+
+    use super::*;
+
+    #[test]
+    fn lines_without_digit_follow_the_policy() {
+        let policy = |missing_digits| CalibrationPolicy::new(&Vocabulary::default(), Rule::FirstAndLast, missing_digits);
+        let input = "a1b\nnothing\ntwone\n";
+        let error = policy(MissingDigits::Error).calibrate(input).unwrap_err();
+        assert_eq!((error.line, error.found.as_str()), (2, "nothing"));
+
+        let skipped = policy(MissingDigits::Skip).calibrate(input).unwrap();
+        assert_eq!((skipped.total, skipped.lines_without_digit.clone()), (11 + 21, vec![2]));
+        assert_eq!(skipped.lines[1].value, None);
+        let zeroed = policy(MissingDigits::Zero).calibrate(input).unwrap();
+        assert_eq!((zeroed.total, zeroed.lines[1].value), (11 + 21, Some(0)));
+    }
+
+    #[test]
+    fn report_marks_overlapping_digits_on_separate_rows() {
+        let policy = CalibrationPolicy::new(&Vocabulary::default(), Rule::FirstAndLast, MissingDigits::Skip);
+        let calibration = policy.calibrate("xtwone3\n-\n").unwrap();
+        assert_eq!(
+            render_report(&calibration),
+            "1 | xtwone3\n  |  222  3\n  |    111\n  = first 2, last 3, value 23\n\
+             2 | -\n  = no digit, skipped\n\
+             2 lines, total 23\n\
+             Lines without a digit (skip): 2\n"
+        );
+    }
+
+    #[test]
+    fn rules_read_different_values_from_the_same_digits() {
+        let input = "x12twone3\n";
+        let value = |rule| CalibrationPolicy::new(&Vocabulary::default(), rule, MissingDigits::Error).calibrate(input).unwrap().total;
+        assert_eq!(value(Rule::FirstAndLast), 13);
+        assert_eq!(value(Rule::SumOfDigits), 1 + 2 + 2 + 1 + 3);
+        assert_eq!(value(Rule::LargestNumber), 12);
+        assert_eq!(value(Rule::FirstAndLastWords), 21);
+        assert_eq!(value(Rule::NearestToColumn(1)), 1);
+        // "one" covers columns 6 to 8 and "two" ends at column 6, the leftmost wins
+        assert_eq!(value(Rule::NearestToColumn(6)), 2);
+        assert_eq!(value(Rule::NearestToColumn(8)), 1);
+        assert_eq!("nearest:8".parse::<Rule>(), Ok(Rule::NearestToColumn(8)));
+    }
+
+    #[test]
+    fn total_beyond_64_bits_is_an_error() {
+        let policy = CalibrationPolicy::new(&Vocabulary::numerals_only(), Rule::LargestNumber, MissingDigits::Error);
+        assert_eq!(policy.calibrate("a18446744073709551615\n").unwrap().total, u64::MAX);
+        let error = policy.calibrate("a18446744073709551615\nb1\n").unwrap_err();
+        assert_eq!(error.line, 2);
+        let error = policy.calibrate("a18446744073709551616\n").unwrap_err();
+        assert_eq!((error.column, error.expected.as_str()), (2, "a number of at most 64 bits"));
+    }
+
+    #[test]
+    fn both_parts_run_under_every_rule() {
+        let input = read_input_file(input_file_path(1, "input.txt")).unwrap();
+        let rules = [Rule::FirstAndLast, Rule::SumOfDigits, Rule::LargestNumber, Rule::FirstAndLastWords, Rule::NearestToColumn(10)];
+        for rule in rules {
+            for part in Part::ALL {
+                match CalibrationPolicy::for_part(part, &Vocabulary::default(), rule, MissingDigits::Skip) {
+                    Ok(policy) => assert!(policy.calibrate(&input).unwrap().total > 0, "rule {} part {}", rule, part),
+                    Err(message) => {
+                        assert!(rule.reads_words_only() && part == Part::One, "rule {} part {}: {}", rule, part, message);
+                        assert!(message.contains("part 1"));
+                    }
+                }
+            }
+        }
+    }
+}
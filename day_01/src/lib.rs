@@ -0,0 +1,40 @@
+pub mod calibration;
+pub mod scanner;
+pub mod vocabulary;
+
+use aoc_common::{AocError, Answer, ParseError, Solution};
+use calibration::CalibrationPolicy;
+use vocabulary::Vocabulary;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    #[cfg(feature = "embedded-input")]
+    const EMBEDDED_INPUT: Option<&'static str> = Some(include_str!("input.txt"));
+
+    type Input<'a> = &'a str;
+
+    fn parse(input_string: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input_string)
+    }
+
+    fn part1(input_string: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part1(input_string)?.into())
+    }
+
+    fn part2(input_string: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part2(input_string, &Vocabulary::default())?.into())
+    }
+}
+
+/// Fails on a line without a numeric digit, which part 1 has no value for.
+pub fn part1(input_string: &str) -> Result<u64, ParseError> {
+    Ok(CalibrationPolicy::part1().calibrate(input_string)?.total)
+}
+
+/// Part 1 with the words of `vocabulary` counting as digits as well, the puzzle uses the default one.
+pub fn part2(input_string: &str, vocabulary: &Vocabulary) -> Result<u64, ParseError> {
+    Ok(CalibrationPolicy::part2(vocabulary).calibrate(input_string)?.total)
+}
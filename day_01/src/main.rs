@@ -1,88 +1,95 @@
-use std::{path::Path, fs::File, io::Read};
+use std::{ffi::OsString, process::ExitCode};
 
-use regex::Regex;
+use aoc_common::{day_main_with_input, InputSource, Part, Solution};
+use clap::Parser;
+use tracing::info_span;
+use day_01::{
+    calibration::{render_report, CalibrationPolicy, MissingDigits, Rule},
+    vocabulary::Vocabulary,
+    Day01,
+};
 
-fn main() {
-    let part1_result = part1(&read_input_file("./src/input.txt"));
-    println!("Part 1: {}", part1_result);
-    let part2_result = part2(&read_input_file("./src/input.txt"));
-    println!("Part 2: {}", part2_result);
-
-    /******************************************************
-     * Output:
-     * Part 1: 57346
-     * Part 2: 57345
-     ******************************************************/
+#[derive(Parser)]
+struct Args {
+    /// Input file, "-" for stdin, defaults to src/input.txt
+    input: Option<OsString>,
+    /// Words counted as digits in part 2: a language (en, de, fr, es) or a vocabulary file
+    #[arg(long)]
+    vocabulary: Option<String>,
+    /// Match the vocabulary regardless of case
+    #[arg(long)]
+    ignore_case: bool,
+    /// Also read words misspelled by at most this many edits as digits in part 2
+    #[arg(long, default_value_t = 0)]
+    fuzzy: usize,
+    /// How a line's digits become its value: first-and-last, sum, largest-number,
+    /// first-and-last-words or nearest:<column>
+    #[arg(long, default_value_t = Rule::FirstAndLast)]
+    rule: Rule,
+    /// What to do with lines without a digit: error, skip or zero
+    #[arg(long, default_value_t = MissingDigits::Error)]
+    missing_digits: MissingDigits,
+    /// Print every line with its digits, the chosen first and last digit and its value
+    #[arg(long)]
+    report: bool,
+    /// Show more diagnostics, repeat for more detail (overridden by the AOC_LOG filter)
+    #[arg(short, long, action = clap::ArgAction::Count)]
+    verbose: u8,
 }
 
-// TODO: move to shared utils lib
-fn read_input_file(input_file_path: &str) -> String {
-    let input_path = Path::new(input_file_path);
-    let mut input_file = match File::open(&input_path) {
-        Err(error) => panic!("Failed to open {} - error: {}", input_path.display(), error),
-        Ok(file) => file        
+fn main() -> ExitCode {
+    let args = Args::parse();
+    let vocabulary = match &args.vocabulary {
+        Some(name) => Vocabulary::language(name).map_or_else(|| Vocabulary::load(name), Ok),
+        None => Ok(Vocabulary::default()),
     };
-    let mut input_string = String::new();
-    match input_file.read_to_string(&mut input_string) {
-        Err(error) => panic!("Failed to read contents of {} - error: {}", input_path.display(), error),
-        Ok(_bytelength) => println!("Successfully read input file {}", input_path.display())
-    }
-    input_string
-}
-
-fn part1(input_string: &String) -> u32 {
-    let digits_per_line: Vec<u32> = input_string
-        .lines()
-        .map(|line| extract_digits(line))
-        .map(|digits| combine_first_and_last_digits(&digits))
-        .collect();
-    let total_sum: u32 = digits_per_line.iter().sum();
-    total_sum
-}
-
-fn extract_digits(line: &str) -> Vec<u32> {
-    line.chars().filter(|c| c.is_numeric()).map(|c| c.to_digit(10).unwrap()).collect::<Vec<u32>>()
-}
-
-fn combine_first_and_last_digits(digits: &Vec<u32>) -> u32 {
-    format!("{}{}", digits.first().unwrap(), digits.last().unwrap()).parse::<u32>().unwrap()
-}
-
-fn part2(input_string: &String) -> u32 {
-    let digits_per_line: Vec<u32> = input_string
-        .lines()
-        .map(|line| insert_numeric_digits_for_spelled_out_digits(line))
-        .map(|line| extract_digits(line.as_str()))
-        .map(|digits| combine_first_and_last_digits(&digits))
-        .collect();
-    let total_sum: u32 = digits_per_line.iter().sum();
-    total_sum
-}
+    let mut vocabulary = match vocabulary {
+        Ok(vocabulary) => vocabulary,
+        Err(error) => {
+            eprintln!("{}", error);
+            return ExitCode::FAILURE;
+        }
+    };
+    vocabulary.case_insensitive |= args.ignore_case;
+    vocabulary.max_edit_distance = args.fuzzy;
 
-/**
- * Append a numerical digit wherever a spelled-out digit is found.
- * Tricky part is when two spelled-out digits overlap, e.g. "twone".
- * By simply replacing "two" with "2" or "two2" the occurrence of "one" would be destroyed.
- * To avoid that, surround the inserted numeric digit with "#", e.g. "two#2#ne".
- * When doing the search-and-replace just ignore all numeric digits enclosed with #.
- * that way all spelled-out digits and their order of occurrence are preserved.
- * Append numeric digits for simple extraction of first and last digit as was sufficient in Part 1.
- */
-fn insert_numeric_digits_for_spelled_out_digits(line: &str) -> String {
-    let mut result = line.to_string();
-    result = Regex::new(r"o(#[1-9]#)?n(#[1-9]#)?e(#[1-9]#)?").unwrap().replace_all(&result, "$0#1#").to_string();
-    result = Regex::new(r"t(#[1-9]#)?w(#[1-9]#)?o(#[1-9]#)?").unwrap().replace_all(&result, "$0#2#").to_string();
-    result = Regex::new(r"t(#[1-9]#)?h(#[1-9]#)?r(#[1-9]#)?e(#[1-9]#)?e(#[1-9]#)?").unwrap().replace_all(&result, "$0#3#").to_string();
-    // ...
-    result = Regex::new(&build_regex_pattern_for_spelled_out_digit("four")).unwrap().replace_all(&result, "$0#4#").to_string();
-    result = Regex::new(&build_regex_pattern_for_spelled_out_digit("five")).unwrap().replace_all(&result, "$0#5#").to_string();
-    result = Regex::new(&build_regex_pattern_for_spelled_out_digit("six")).unwrap().replace_all(&result, "$0#6#").to_string();
-    result = Regex::new(&build_regex_pattern_for_spelled_out_digit("seven")).unwrap().replace_all(&result, "$0#7#").to_string();
-    result = Regex::new(&build_regex_pattern_for_spelled_out_digit("eight")).unwrap().replace_all(&result, "$0#8#").to_string();
-    result = Regex::new(&build_regex_pattern_for_spelled_out_digit("nine")).unwrap().replace_all(&result, "$0#9#").to_string();
-    result
-}
+    // a part that fails is reported without stopping the other, part 1 fails on every line that
+    // only spells its digits out
+    let mut failed = false;
+    let input_path = args.input.clone()
+        .map(InputSource::from_arg)
+        .unwrap_or_else(|| InputSource::default_for_day(Day01::DAY, Day01::EMBEDDED_INPUT))
+        .display_path();
+    let exit_code = day_main_with_input::<Day01>(args.input, args.verbose, |input_string| {
+        for part in Part::ALL {
+            // the same span the runner solves a part in
+            let _span = info_span!("part", day = Day01::DAY, part = part.number()).entered();
+            let policy = match CalibrationPolicy::for_part(part, &vocabulary, args.rule, args.missing_digits) {
+                Ok(policy) => policy,
+                Err(message) => {
+                    eprintln!("Part {}: {}", part, message);
+                    continue;
+                }
+            };
+            let calibration = match policy.calibrate(input_string) {
+                Ok(calibration) => calibration,
+                Err(error) => {
+                    eprintln!("Part {}: {}", part, error.with_file(&input_path));
+                    failed = true;
+                    continue;
+                }
+            };
+            if args.report {
+                println!("Part {} report:", part);
+                print!("{}", render_report(&calibration));
+            } else if !calibration.lines_without_digit.is_empty() {
+                let numbers = calibration.lines_without_digit.iter().map(|number| number.to_string()).collect::<Vec<_>>();
+                eprintln!("Part {}: lines without a digit ({}): {}", part, args.missing_digits, numbers.join(", "));
+            }
+            println!("Part {}: {}", part, calibration.total);
+        }
 
-fn build_regex_pattern_for_spelled_out_digit(digit_name: &str) -> String {
-    digit_name.chars().map(|c| format!("{}{}", c, "(#[1-9]#)?")).collect::<Vec<_>>().join("")
+        Ok(())
+    });
+    if failed { ExitCode::FAILURE } else { exit_code }
 }
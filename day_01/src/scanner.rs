@@ -0,0 +1,243 @@
+use aho_corasick::AhoCorasick;
+
+use crate::vocabulary::Vocabulary;
+
+/// Patterns of the numeric digits, which come before the words.
+const NUMERALS: usize = 10;
+/// Characters a word needs per edit it may be misspelled by, and the fewest a fuzzy match may have,
+/// so that fragments like "on" or "tw" are never read as digits.
+const MIN_FUZZY_LENGTH: usize = 4;
+
+/// A digit found on a line, at the byte range `start..end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Token {
+    pub digit: u32,
+    pub start: usize,
+    pub end: usize,
+    /// Spelled out by a word of the vocabulary rather than written as a numeric digit.
+    pub word: bool,
+    /// Set when the word is misspelled on the line.
+    pub fuzzy: Option<FuzzyMatch>,
+}
+
+/// How far a misspelled word on a line is from the vocabulary word it was read as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FuzzyMatch {
+    /// Edits between the two, counting a swap of neighboring characters as one.
+    pub distance: usize,
+    /// Length of the vocabulary word in characters.
+    pub word_length: usize,
+}
+
+impl FuzzyMatch {
+    /// Share of the vocabulary word that did not need an edit, from 0 to 1.
+    pub fn confidence(&self) -> f64 {
+        1.0 - self.distance as f64 / self.word_length as f64
+    }
+}
+
+/// Finds every numeric digit and every word of a vocabulary on a line in a single pass.
+///
+/// All words are matched at once by an Aho-Corasick automaton that is built only once,
+/// and overlapping words like "twone" or "eightwo" are all reported.
+pub struct Scanner {
+    automaton: AhoCorasick,
+    /// Digit of every pattern of the automaton, by pattern index.
+    digits: Vec<u32>,
+    case_insensitive: bool,
+    /// Words that may be misspelled with the most edits each one allows, in the order of the vocabulary.
+    fuzzy_words: Vec<(Vec<char>, u32, usize)>,
+}
+
+impl Scanner {
+    /// A scanner for the numeric digits 0 to 9 and the words of `vocabulary`.
+    pub fn new(vocabulary: &Vocabulary) -> Self {
+        let numerals = (0..NUMERALS as u32).map(|digit| (digit.to_string(), digit));
+        let words = vocabulary.words.iter()
+            .map(|(word, digit)| (if vocabulary.case_insensitive { word.to_lowercase() } else { word.clone() }, *digit))
+            .collect::<Vec<_>>();
+        // a word is only matched fuzzily if most of it is left as it is, so short words allow fewer edits
+        let fuzzy_words = words.iter()
+            .map(|(word, digit)| {
+                let word = word.chars().collect::<Vec<_>>();
+                let max_distance = vocabulary.max_edit_distance.min(word.len() / MIN_FUZZY_LENGTH);
+                (word, *digit, max_distance)
+            })
+            .filter(|&(_, _, max_distance)| max_distance > 0)
+            .collect();
+        let (patterns, digits): (Vec<_>, Vec<_>) = numerals.chain(words).unzip();
+        let automaton = AhoCorasick::new(&patterns).expect("digit patterns are valid");
+        Self {
+            automaton,
+            digits,
+            case_insensitive: vocabulary.case_insensitive,
+            fuzzy_words,
+        }
+    }
+
+    /// Every digit on the line in order of where it starts.
+    pub fn scan(&self, line: &str) -> Vec<Token> {
+        let folded = self.case_insensitive.then(|| fold_case(line));
+        let text = folded.as_ref().map_or(line, |(folded, _)| folded.as_str());
+        let mut tokens = self.find(text).collect::<Vec<_>>();
+        if !self.fuzzy_words.is_empty() {
+            let fuzzy_tokens = self.find_fuzzy(text, &tokens);
+            tokens.extend(fuzzy_tokens);
+        }
+        if let Some((_, offsets)) = &folded {
+            for token in &mut tokens {
+                (token.start, token.end) = (offsets[token.start], offsets[token.end]);
+            }
+        }
+        // the automaton reports matches in order of where they end
+        tokens.sort_unstable_by_key(|token| (token.start, token.end));
+        tokens
+    }
+
+    fn find<'a>(&'a self, line: &'a str) -> impl Iterator<Item = Token> + 'a {
+        self.automaton.find_overlapping_iter(line)
+            .map(|found| {
+                let pattern = found.pattern().as_usize();
+                Token { digit: self.digits[pattern], start: found.start(), end: found.end(), word: pattern >= NUMERALS, fuzzy: None }
+            })
+    }
+
+    /// Misspelled words that do not overlap a digit found exactly or each other.
+    ///
+    /// Of two that would overlap the closer one wins, then the one further left and then the
+    /// one whose word comes first in the vocabulary, so the outcome never depends on chance.
+    fn find_fuzzy(&self, line: &str, exact_tokens: &[Token]) -> Vec<Token> {
+        let chars = line.char_indices().collect::<Vec<_>>();
+        let offset = |index: usize| chars.get(index).map_or(line.len(), |&(offset, _)| offset);
+        let text = chars.iter().map(|&(_, c)| c).collect::<Vec<_>>();
+        let mut candidates = vec![];
+        for start in 0..text.len() {
+            for (index, (word, digit, max_distance)) in self.fuzzy_words.iter().enumerate() {
+                if let Some((distance, length)) = closest_alignment(word, &text[start..], *max_distance) {
+                    let fuzzy = Some(FuzzyMatch { distance, word_length: word.len() });
+                    let token = Token { digit: *digit, start: offset(start), end: offset(start + length), word: true, fuzzy };
+                    candidates.push((distance, start, index, token));
+                }
+            }
+        }
+        candidates.sort_unstable_by_key(|&(distance, start, index, _)| (distance, start, index));
+
+        let mut accepted: Vec<Token> = vec![];
+        for (_, _, _, token) in candidates {
+            if exact_tokens.iter().chain(&accepted).all(|other| other.end <= token.start || token.end <= other.start) {
+                accepted.push(token);
+            }
+        }
+        accepted
+    }
+}
+
+/// Edit distance and length of the start of `text` closest to `word`, if it takes at least one
+/// and at most `max_distance` edits and is at least [`MIN_FUZZY_LENGTH`] characters long.
+/// Of starts as close, the one as long as the word is preferred.
+fn closest_alignment(word: &[char], text: &[char], max_distance: usize) -> Option<(usize, usize)> {
+    let text = &text[..text.len().min(word.len() + max_distance)];
+    // distances[i][j] between the first i characters of the word and the first j of the text
+    let mut distances = vec![vec![0; text.len() + 1]; word.len() + 1];
+    for i in 0..=word.len() {
+        for j in 0..=text.len() {
+            distances[i][j] = if i == 0 || j == 0 {
+                i + j
+            } else {
+                let substitution = distances[i - 1][j - 1] + usize::from(word[i - 1] != text[j - 1]);
+                let mut distance = substitution.min(distances[i - 1][j] + 1).min(distances[i][j - 1] + 1);
+                if i > 1 && j > 1 && word[i - 1] == text[j - 2] && word[i - 2] == text[j - 1] {
+                    distance = distance.min(distances[i - 2][j - 2] + 1);
+                }
+                distance
+            };
+        }
+    }
+    (word.len().saturating_sub(max_distance).max(MIN_FUZZY_LENGTH)..=text.len())
+        .map(|length| (distances[word.len()][length], length))
+        .filter(|&(distance, _)| (1..=max_distance).contains(&distance))
+        .min_by_key(|&(distance, length)| (distance, length.abs_diff(word.len()), length))
+}
+
+/// The line in lower case, with the offset in the original line of every byte of the lower case
+/// one and of its end. Lowering a character can change its length, e.g. 'İ' becomes "i̇".
+fn fold_case(line: &str) -> (String, Vec<usize>) {
+    let mut folded = String::with_capacity(line.len());
+    let mut offsets = Vec::with_capacity(line.len() + 1);
+    for (offset, c) in line.char_indices() {
+        folded.extend(c.to_lowercase());
+        offsets.resize(folded.len(), offset);
+    }
+    offsets.push(line.len());
+    (folded, offsets)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{input_file_path, read_input_file};
+
+    use super::*;
+    use crate::part2;
+
+    #[test]
+    fn scanner_reports_overlapping_words() {
+        let scanner = Scanner::new(&Vocabulary::default());
+        let digits = scanner.scan("xtwone3eightwo").iter().map(|token| token.digit).collect::<Vec<_>>();
+        assert_eq!(digits, vec![2, 1, 3, 8, 2]);
+        assert_eq!(scanner.scan("eightwo")[1], Token { digit: 2, start: 4, end: 7, word: true, fuzzy: None });
+    }
+
+    #[test]
+    fn scanner_matches_multilingual_words_regardless_of_case() {
+        let scanner = Scanner::new(&Vocabulary::language("de").unwrap());
+        let tokens = scanner.scan("xFÜNFnullacht");
+        let digits = tokens.iter().map(|token| token.digit).collect::<Vec<_>>();
+        assert_eq!(digits, vec![5, 0, 8]);
+        // the offsets point into the line as given, "Ü" taking two bytes
+        assert_eq!((tokens[0].start, tokens[0].end), (1, 6));
+    }
+
+    #[test]
+    fn fuzzy_mode_reads_misspelled_words() {
+        let mut vocabulary = Vocabulary::default();
+        let digits = |vocabulary: &Vocabulary, line| Scanner::new(vocabulary).scan(line).iter().map(|token| token.digit).collect::<Vec<_>>();
+        assert_eq!(digits(&vocabulary, "xnien3sevnfuor"), vec![3]);
+
+        vocabulary.max_edit_distance = 1;
+        assert_eq!(digits(&vocabulary, "xnien3sevnfuor"), vec![9, 3, 7, 4]);
+        let nien = Scanner::new(&vocabulary).scan("xnien3")[0];
+        assert_eq!((nien.start, nien.end), (1, 5));
+        assert_eq!(nien.fuzzy.unwrap().confidence(), 0.75);
+        // "fine" is one edit from both five and nine, five comes first in the vocabulary
+        assert_eq!(digits(&vocabulary, "fine"), vec![5]);
+        // exact words keep their overlaps and win over misspelled ones
+        assert_eq!(digits(&vocabulary, "twone"), vec![2, 1]);
+    }
+
+    #[test]
+    fn fuzzy_mode_caps_the_edits_by_word_length() {
+        let vocabulary = Vocabulary { max_edit_distance: 3, ..Vocabulary::default() };
+        // a higher tolerance still reads the words that allow one edit
+        assert_eq!(part2("xnien3sevnfuor\n", &vocabulary).unwrap(), 94);
+
+        let mut vocabulary = Vocabulary::parse("7 lucky seven\n").unwrap();
+        vocabulary.max_edit_distance = 2;
+        let tokens = Scanner::new(&vocabulary).scan("lucki sevne");
+        assert_eq!(tokens.len(), 1);
+        assert_eq!((tokens[0].digit, tokens[0].fuzzy.unwrap().distance), (7, 2));
+        vocabulary.max_edit_distance = 1;
+        assert!(Scanner::new(&vocabulary).scan("lucki sevne").is_empty());
+    }
+
+    #[test]
+    fn fuzzy_mode_ignores_short_fragments() {
+        let vocabulary = Vocabulary { max_edit_distance: 1, ..Vocabulary::default() };
+        let scanner = Scanner::new(&vocabulary);
+        for fragment in ["on", "si", "tw", "th", "fi", "ni", "ei", "se", "xonx", "fiv"] {
+            assert_eq!(scanner.scan(fragment), vec![], "{}", fragment);
+        }
+
+        let input = read_input_file(input_file_path(1, "input.txt")).unwrap();
+        assert_eq!(part2(&input, &vocabulary).unwrap(), part2(&input, &Vocabulary::default()).unwrap());
+    }
+}
@@ -0,0 +1,161 @@
+use std::{fs, path::Path};
+
+use aoc_common::{parse::{first_word, parse_number}, AocError, ParseError};
+
+/// The spelled-out digits counted by part 2.
+pub const ENGLISH_DIGITS: [(&str, u32); 9] = [
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+];
+
+const ENGLISH_DIGITS_FROM_ZERO: [(&str, u32); 10] = [
+    ("zero", 0),
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+];
+
+const GERMAN_DIGITS: [(&str, u32); 10] = [
+    ("null", 0),
+    ("eins", 1),
+    ("zwei", 2),
+    ("drei", 3),
+    ("vier", 4),
+    ("fünf", 5),
+    ("sechs", 6),
+    ("sieben", 7),
+    ("acht", 8),
+    ("neun", 9),
+];
+
+const FRENCH_DIGITS: [(&str, u32); 10] = [
+    ("zéro", 0),
+    ("un", 1),
+    ("deux", 2),
+    ("trois", 3),
+    ("quatre", 4),
+    ("cinq", 5),
+    ("six", 6),
+    ("sept", 7),
+    ("huit", 8),
+    ("neuf", 9),
+];
+
+const SPANISH_DIGITS: [(&str, u32); 10] = [
+    ("cero", 0),
+    ("uno", 1),
+    ("dos", 2),
+    ("tres", 3),
+    ("cuatro", 4),
+    ("cinco", 5),
+    ("seis", 6),
+    ("siete", 7),
+    ("ocho", 8),
+    ("nueve", 9),
+];
+
+/// Words that stand for a digit on a calibration line. Words may contain spaces and may
+/// overlap each other, e.g. "twone" holds both a two and a one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Vocabulary {
+    pub words: Vec<(String, u32)>,
+    /// Match the words regardless of case, for every script that has one.
+    pub case_insensitive: bool,
+    /// Also read a misspelled word as its digit, if it takes at most this many edits to
+    /// correct it. 0 only matches words spelled exactly. A word allows one edit per four
+    /// characters at most, so "one" or "six" are never misspelled and "seven" only once.
+    pub max_edit_distance: usize,
+}
+
+/// The English words one to nine, matched case-sensitively as the puzzle demands.
+impl Default for Vocabulary {
+    fn default() -> Self {
+        Self::new(ENGLISH_DIGITS, false)
+    }
+}
+
+impl Vocabulary {
+    pub fn new<W: Into<String>>(words: impl IntoIterator<Item = (W, u32)>, case_insensitive: bool) -> Self {
+        Self { words: words.into_iter().map(|(word, digit)| (word.into(), digit)).collect(), case_insensitive, max_edit_distance: 0 }
+    }
+
+    /// No words at all, so only numeric digits count.
+    pub fn numerals_only() -> Self {
+        Self::new(Vec::<(String, u32)>::new(), false)
+    }
+
+    /// A built-in word set by language name or code, from zero to nine. The built-in sets ignore
+    /// case, and only the default vocabulary keeps to the puzzle's one to nine.
+    pub fn language(name: &str) -> Option<Self> {
+        let words: &[(&str, u32)] = match name.to_lowercase().as_str() {
+            "en" | "english" => &ENGLISH_DIGITS_FROM_ZERO,
+            "de" | "german" => &GERMAN_DIGITS,
+            "fr" | "french" => &FRENCH_DIGITS,
+            "es" | "spanish" => &SPANISH_DIGITS,
+            _ => return None,
+        };
+        Some(Self::new(words.iter().copied(), true))
+    }
+
+    /// Parse a vocabulary file: one word per line after the digit it stands for, e.g. "7 sieben",
+    /// with blank lines and lines starting with `#` ignored.
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        let mut words = vec![];
+        for line in text.lines().map(str::trim).filter(|line| !line.is_empty() && !line.starts_with('#')) {
+            let digit_token = first_word(line);
+            let digit = parse_number::<u32>(text, digit_token)?;
+            if digit > 9 {
+                return Err(ParseError::at(text, digit_token, "digit from 0 to 9"));
+            }
+            let word = line[digit_token.len()..].trim();
+            if word.is_empty() {
+                return Err(ParseError::at(text, &line[line.len()..], "word after the digit"));
+            }
+            words.push((word.to_string(), digit));
+        }
+        Ok(Self::new(words, false))
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, AocError> {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path).map_err(|source| AocError::Io { path: path.to_path_buf(), source })?;
+        Self::parse(&text).map_err(|error| AocError::from(error).with_input_file(path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{part2, scanner::Scanner};
+
+    #[test]
+    fn english_language_reads_zero_unlike_the_default() {
+        let english = Scanner::new(&Vocabulary::language("en").unwrap());
+        let digits = english.scan("ZeroOne").iter().map(|token| token.digit).collect::<Vec<_>>();
+        assert_eq!(digits, vec![0, 1]);
+        assert!(Scanner::new(&Vocabulary::default()).scan("zero").is_empty());
+    }
+
+    #[test]
+    fn vocabulary_file_allows_zero_and_words_with_spaces() {
+        let vocabulary = Vocabulary::parse("# digits\n0 zero\n7 lucky seven\n\n1 one\n").unwrap();
+        assert_eq!(vocabulary.words, vec![("zero".to_string(), 0), ("lucky seven".to_string(), 7), ("one".to_string(), 1)]);
+        assert_eq!(part2("zero lucky seven\nxoneight\n", &vocabulary).unwrap(), 7 + 11);
+
+        let error = Vocabulary::parse("1 one\n12 twelve\n").unwrap_err();
+        assert_eq!((error.line, error.column, error.expected.as_str()), (2, 1, "digit from 0 to 9"));
+    }
+}
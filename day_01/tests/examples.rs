@@ -0,0 +1,19 @@
+use aoc_common::{input_file_path, read_input_file, testing::assert_example, Part};
+use day_01::{part2, vocabulary::Vocabulary, Day01};
+
+#[test]
+fn part1_example() {
+    assert_example::<Day01>("input_example_1.txt", Part::One, 142);
+}
+
+#[test]
+fn part2_example() {
+    assert_example::<Day01>("input_example_2.txt", Part::Two, 281);
+}
+
+#[test]
+fn part2_is_unchanged_without_fuzzy_mode() {
+    let input = read_input_file(input_file_path(1, "input_example_2.txt")).unwrap();
+    let vocabulary = Vocabulary { max_edit_distance: 0, ..Vocabulary::default() };
+    assert_eq!(part2(&input, &vocabulary).unwrap(), 281);
+}
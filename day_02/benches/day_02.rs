@@ -0,0 +1,6 @@
+use aoc_common::bench::bench_solution;
+use criterion::{criterion_group, criterion_main};
+use day_02::Day02;
+
+criterion_group!(benches, bench_solution::<Day02>);
+criterion_main!(benches);
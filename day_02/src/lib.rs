@@ -0,0 +1,86 @@
+use aoc_common::{parse::{parse_lines, parse_number, split_once, strip_prefix}, AocError, Answer, ParseError, Solution};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    #[cfg(feature = "embedded-input")]
+    const EMBEDDED_INPUT: Option<&'static str> = Some(include_str!("input.txt"));
+
+    type Input<'a> = Vec<Game>;
+
+    fn parse(input_string: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(parse_games(input_string)?)
+    }
+
+    fn part1(games: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part1(games).into())
+    }
+
+    fn part2(games: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part2(games).into())
+    }
+}
+
+pub fn part1(games: &[Game]) -> u32 {
+    games
+        .iter()
+        .filter(|&game| is_game_possible(game, 12, 13, 14))
+        .map(|game| game.id)
+        .sum()
+}
+
+pub fn part2(games: &[Game]) -> u32 {
+    games
+        .iter()
+        .map(minimum_cubes)
+        .map(|(red,green,blue)| red * green * blue)
+        .sum()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Game {
+    id: u32,
+    reveals: Vec<(u32, u32, u32)>
+}
+
+pub fn parse_games(input_string: &str) -> Result<Vec<Game>, ParseError> {
+    parse_lines(input_string, parse_game)
+}
+
+pub fn parse_game(line: &str) -> Result<Game, ParseError> {
+    let (game_label, reveals_string) = split_once(line, line, ":")?;
+    let game_id = parse_number(line, strip_prefix(line, game_label, "Game ")?)?;
+    let reveals = reveals_string
+        .split(';')
+        .map(|reveal_string| parse_reveal(line, reveal_string))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(Game { id: game_id, reveals })
+}
+
+fn parse_reveal(line: &str, reveal_string: &str) -> Result<(u32, u32, u32), ParseError> {
+    let (mut red_cubes, mut green_cubes, mut blue_cubes) = (0, 0, 0); // if a color is not given explicitly, no cubes of it were revealed
+    for cubes_string in reveal_string.split(',').map(str::trim) {
+        let (number_string, color) = cubes_string.split_once(' ').unwrap_or((cubes_string, &cubes_string[cubes_string.len()..]));
+        let number_of_cubes = parse_number(line, number_string)?;
+        match color {
+            "red" => red_cubes = number_of_cubes,
+            "green" => green_cubes = number_of_cubes,
+            "blue" => blue_cubes = number_of_cubes,
+            _ => return Err(ParseError::at(line, color, "\"red\", \"green\" or \"blue\"")),
+        }
+    }
+    Ok((red_cubes, green_cubes, blue_cubes))
+}
+
+fn is_game_possible(game: &Game, max_red: u32, max_green: u32, max_blue: u32) -> bool {
+    game.reveals.iter().all(|&(red,green,blue)| red <= max_red && green <= max_green && blue <= max_blue)
+}
+
+fn minimum_cubes(game: &Game) -> (u32, u32, u32) {
+    let min_red = game.reveals.iter().map(|&(red,_,_)| red).max().unwrap();
+    let min_green = game.reveals.iter().map(|&(_,green,_)| green).max().unwrap();
+    let min_blue = game.reveals.iter().map(|&(_,_,blue)| blue).max().unwrap();
+    (min_red, min_green, min_blue)
+}
@@ -0,0 +1,12 @@
+use aoc_common::{testing::assert_example, Part};
+use day_02::Day02;
+
+#[test]
+fn part1_example() {
+    assert_example::<Day02>("input_example.txt", Part::One, 8);
+}
+
+#[test]
+fn part2_example() {
+    assert_example::<Day02>("input_example.txt", Part::Two, 2286);
+}
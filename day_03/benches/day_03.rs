@@ -0,0 +1,6 @@
+use aoc_common::bench::bench_solution;
+use criterion::{criterion_group, criterion_main};
+use day_03::Day03;
+
+criterion_group!(benches, bench_solution::<Day03>);
+criterion_main!(benches);
@@ -0,0 +1,96 @@
+use aoc_common::{AocError, Answer, Grid, ParseError, Solution};
+
+pub struct Day03;
+
+/// The schematic and the positions of the symbols in it.
+pub type SchematicAndSymbols = (Grid<char>, Vec<(usize, usize)>);
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    #[cfg(feature = "embedded-input")]
+    const EMBEDDED_INPUT: Option<&'static str> = Some(include_str!("input.txt"));
+
+    type Input<'a> = SchematicAndSymbols;
+
+    fn parse(input_string: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(parse_schematic_and_symbol_positions(input_string)?)
+    }
+
+    fn part1((schematic, symbol_positions): &Self::Input<'_>) -> Result<Answer, AocError> {
+        // partnumbers are consumed while solving, so work on a copy of the schematic
+        Ok(part1(&mut schematic.clone(), symbol_positions).into())
+    }
+
+    fn part2((schematic, symbol_positions): &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part2(&mut schematic.clone(), symbol_positions).into())
+    }
+}
+
+pub fn parse_schematic_and_symbol_positions(input_string: &str) -> Result<SchematicAndSymbols, ParseError> {
+    let schematic = Grid::parse(input_string, Some, "any character")?;
+    let symbol_positions = schematic.find_all(|&c| !c.is_numeric() && c != '.').collect();
+    Ok((schematic, symbol_positions))
+}
+
+pub fn part1(schematic: &mut Grid<char>, symbol_positions: &[(usize,usize)]) -> usize {
+    let mut partnumber_sum: usize = 0;
+    for &symbol_position in symbol_positions {
+        let adjacent_partnumbers = find_adjacent_partnumbers_for_symbol(schematic, symbol_position);
+        partnumber_sum += adjacent_partnumbers.iter().sum::<usize>();
+    }
+    partnumber_sum
+}
+
+pub fn part2(schematic: &mut Grid<char>, symbol_positions: &[(usize,usize)]) -> usize {
+    let mut gear_ratio: usize = 0;
+    for &position in symbol_positions {
+        if schematic[position] == '*' {
+            let adjacent_partnumbers = find_adjacent_partnumbers_for_symbol(schematic, position);
+            if adjacent_partnumbers.len() > 1 {
+                // "A gear is any * symbol that is adjacent to exactly two part numbers." 
+                gear_ratio += adjacent_partnumbers.iter().product::<usize>();
+            }
+        }
+    }
+    gear_ratio
+}
+
+fn find_adjacent_partnumbers_for_symbol(schematic: &mut Grid<char>, symbol_position: (usize, usize)) -> Vec<usize> {
+    let adjacent_positions = schematic.neighbors8(symbol_position).collect::<Vec<(usize, usize)>>();
+
+    let mut partnumbers = vec![];
+    for position in adjacent_positions {
+        // the digits of a partnumber are consumed once read, so it is not found again through another neighbor
+        if schematic[position].is_numeric() {
+            let partnumber = read_and_consume_partnumber_at(schematic, position);
+            partnumbers.push(partnumber);
+        }
+    }
+    partnumbers
+}
+
+fn read_and_consume_partnumber_at(schematic: &mut Grid<char>, partnumber_position: (usize, usize)) -> usize {
+    let (x,y) = partnumber_position;
+    let mut partnumber = schematic[(x,y)].to_string();
+    schematic[(x,y)] = '.';
+    // find, append, consume digits to the right
+    for i in y+1..schematic.width() {
+        if schematic[(x,i)].is_numeric() {
+            partnumber.push(schematic[(x,i)]);
+            schematic[(x,i)] = '.';
+            continue;
+        }
+        break;
+    }
+    // find, prepend, consume digits to the left
+    for i in (0..y).rev() {
+        if schematic[(x,i)].is_numeric() {
+            partnumber = format!("{}{}", schematic[(x,i)], partnumber);
+            schematic[(x,i)] = '.';
+            continue;
+        }
+        break;
+    }
+    partnumber.parse().unwrap()
+}
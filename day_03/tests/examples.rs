@@ -0,0 +1,12 @@
+use aoc_common::{testing::assert_example, Part};
+use day_03::Day03;
+
+#[test]
+fn part1_example() {
+    assert_example::<Day03>("input_example.txt", Part::One, 4361);
+}
+
+#[test]
+fn part2_example() {
+    assert_example::<Day03>("input_example.txt", Part::Two, 467835);
+}
@@ -0,0 +1,71 @@
+use aoc_common::{parse::{parse_lines, parse_number, parse_numbers, split_once, strip_prefix}, AocError, Answer, ParseError, Solution};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    #[cfg(feature = "embedded-input")]
+    const EMBEDDED_INPUT: Option<&'static str> = Some(include_str!("input.txt"));
+
+    type Input<'a> = Vec<Card>;
+
+    fn parse(input_string: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(parse_cards(input_string)?)
+    }
+
+    fn part1(cards: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part1(cards).into())
+    }
+
+    fn part2(cards: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part2(cards).into())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Card {
+    id: usize,
+    winning: Vec<usize>,
+    numbers: Vec<usize>,
+    matches: Vec<usize>,
+    matchcount: usize
+}
+
+pub fn parse_cards(input_string: &str) -> Result<Vec<Card>, ParseError> {
+    parse_lines(input_string, parse_card)
+}
+
+fn parse_card(line: &str) -> Result<Card, ParseError> {
+    let (card_label, numbers_string) = split_once(line, line, ":")?;
+    let card_id: usize = parse_number(line, strip_prefix(line, card_label, "Card")?.trim_start())?;
+    let (winning_numbers_string, numbers_string) = split_once(line, numbers_string, "|")?;
+    let winning_numbers: Vec<usize> = parse_numbers(line, winning_numbers_string)?;
+    let numbers: Vec<usize> = parse_numbers(line, numbers_string)?;
+    let matches = numbers.iter().filter(|&&num| winning_numbers.contains(&num)).copied().collect::<Vec<_>>();
+    let matchcount = matches.len();
+    Ok(Card { id: card_id, winning: winning_numbers, numbers, matches, matchcount })
+}
+
+pub fn part1(cards: &[Card]) -> usize {
+    cards
+        .iter()
+        .filter(|&c| c.matchcount > 0)
+        .map(|c| usize::pow(2, (c.matchcount - 1) as u32))
+        .sum()
+}
+
+pub fn part2(cards: &[Card]) -> usize {
+    let mut card_count_by_id = vec![1; cards.len()];
+    cards
+        .iter()
+        .enumerate()
+        .map(|(id, card)| (id, card.matchcount))
+        .map(|(id, matchcount)| (id, id+1, id+matchcount))
+        .map(|(id, next_id, last_id)| (id, (next_id..=last_id)))
+        .for_each(|(id, next_ids)| {
+            // increase the count of each next-N-cards by the number of cards for current id
+            next_ids.into_iter().for_each(|next_id| card_count_by_id[next_id] += card_count_by_id[id]);
+        });
+    card_count_by_id.iter().sum()
+}
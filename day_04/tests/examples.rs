@@ -0,0 +1,12 @@
+use aoc_common::{testing::assert_example, Part};
+use day_04::Day04;
+
+#[test]
+fn part1_example() {
+    assert_example::<Day04>("input_example.txt", Part::One, 13);
+}
+
+#[test]
+fn part2_example() {
+    assert_example::<Day04>("input_example.txt", Part::Two, 30);
+}
@@ -0,0 +1,173 @@
+use std::ops::Range;
+
+use aoc_common::{parse::{parse_numbers, strip_prefix}, AocError, Answer, ParseError, Solution};
+use tracing::trace;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    #[cfg(feature = "embedded-input")]
+    const EMBEDDED_INPUT: Option<&'static str> = Some(include_str!("input.txt"));
+
+    type Input<'a> = Almanac;
+
+    fn parse(input_string: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(parse_almanac(input_string)?)
+    }
+
+    fn part1(almanac: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part1(almanac).into())
+    }
+
+    fn part2(almanac: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part2(almanac).into())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Almanac {
+    seeds: Vec<usize>,
+    maps: Vec<Vec<(usize,usize,usize)>>
+}
+
+const MAP_TITLES: [&str; 7] = [
+    "seed-to-soil map:",
+    "soil-to-fertilizer map:",
+    "fertilizer-to-water map:",
+    "water-to-light map:",
+    "light-to-temperature map:",
+    "temperature-to-humidity map:",
+    "humidity-to-location map:",
+];
+
+pub fn parse_almanac(input_string: &str) -> Result<Almanac, ParseError> {
+    let mut input_sections = input_string.split("\n\n");
+    let seeds = parse_seeds(input_string, input_sections.next().unwrap_or_default())?;
+    let maps = MAP_TITLES
+        .iter()
+        .map(|&title| {
+            let section = input_sections.next().ok_or_else(|| ParseError::at_end(input_string, format!("{:?}", title)))?;
+            parse_map(input_string, section, title)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if let Some(section) = input_sections.next() {
+        return Err(ParseError::at(input_string, section, "end of input after the last map"));
+    }
+    Ok(Almanac { seeds, maps })
+}
+
+fn parse_seeds(input_string: &str, section: &str) -> Result<Vec<usize>, ParseError> {
+    parse_numbers(input_string, strip_prefix(input_string, section, "seeds:")?)
+}
+
+fn parse_map(input_string: &str, section: &str, title: &str) -> Result<Vec<(usize,usize,usize)>, ParseError> {
+    let mut lines = section.lines();
+    let title_line = lines.next().unwrap_or_default();
+    if title_line != title {
+        return Err(ParseError::at(input_string, title_line, format!("{:?}", title)));
+    }
+    lines
+        .map(|line| {
+            let values = parse_numbers::<usize>(input_string, line)?;
+            let &[destination_range_start, source_range_start, range_length] = values.as_slice() else {
+                return Err(ParseError::at(input_string, line, "destination range start, source range start and range length"));
+            };
+            // ranges reaching beyond usize::MAX would overflow while converting
+            if destination_range_start.checked_add(range_length).is_none() || source_range_start.checked_add(range_length).is_none() {
+                return Err(ParseError::at(input_string, line, "range that fits into usize"));
+            }
+            Ok((destination_range_start, source_range_start, range_length))
+        })
+        .collect()
+}
+
+pub fn part1(almanac: &Almanac) -> usize {
+    almanac
+        .seeds
+        .iter()
+        .map(|&seed| convert_seed_to_location(seed, almanac))
+        .min()
+        .unwrap()
+}
+
+fn convert_seed_to_location(seed: usize, almanac: &Almanac) -> usize {
+    let mut value = seed;
+    for (map, title) in almanac.maps.iter().zip(MAP_TITLES) {
+        for &(destination_range_start, source_range_start, range_length) in map {
+            if (source_range_start .. source_range_start + range_length).contains(&value) {
+                value = (value - source_range_start) + destination_range_start;
+                break; // no need to check other mappings if one did already match
+            }
+        }
+        trace!(seed, stage = title.trim_end_matches(" map:"), value, "mapped");
+    }
+    value
+}
+
+pub fn part2(almanac: &Almanac) -> usize {
+    let mut location = 0;
+    let ranges = seed_ranges(almanac);
+    loop {
+        let seed = convert_location_to_seed(location, almanac);
+        if is_seed_in_ranges(seed, &ranges) {
+            break location;
+        }
+        location += 1;
+    }
+}
+
+fn convert_location_to_seed(location: usize, almanac: &Almanac) -> usize {
+    let mut value = location;
+    for map in almanac.maps.iter().rev() {
+        for &(destination_range_start, source_range_start, range_length) in map {
+            if (destination_range_start .. destination_range_start + range_length).contains(&value) {
+                value = (value - destination_range_start) + source_range_start;
+                break; // no need to check other mappings if one did already match
+            }
+        }
+    }
+    value
+}
+
+fn is_seed_in_ranges(seed: usize, ranges: &[Range<usize>]) -> bool {
+    ranges.iter().any(|range| range.contains(&seed))
+}
+
+fn seed_ranges(almanac: &Almanac) -> Vec<Range<usize>> {
+    almanac.seeds.chunks(2).map(|chunk| chunk[0] .. chunk[0]+chunk[1]).collect()
+}
+
+/// Same answer as `part2`, but the seed ranges are pushed through the maps as a whole,
+/// split wherever a mapping covers only part of them, instead of trying one location after another.
+pub fn part2_by_ranges(almanac: &Almanac) -> usize {
+    let mut ranges = seed_ranges(almanac);
+    for map in &almanac.maps {
+        ranges = ranges.into_iter().flat_map(|range| convert_range(range, map)).collect();
+    }
+    ranges.iter().filter(|range| !range.is_empty()).map(|range| range.start).min().unwrap()
+}
+
+fn convert_range(range: Range<usize>, map: &[(usize,usize,usize)]) -> Vec<Range<usize>> {
+    let mut converted = vec![];
+    let mut unmapped = vec![range];
+    for &(destination_range_start, source_range_start, range_length) in map {
+        let source_range_end = source_range_start + range_length;
+        // the part overlapping this mapping is converted, what lies before or after it is left to the next mappings
+        unmapped = unmapped.into_iter()
+            .flat_map(|range| {
+                let start = range.start.max(source_range_start);
+                let end = range.end.min(source_range_end);
+                if start >= end {
+                    return vec![range];
+                }
+                converted.push(start - source_range_start + destination_range_start .. end - source_range_start + destination_range_start);
+                vec![range.start .. start, end .. range.end]
+            })
+            .filter(|range| !range.is_empty())
+            .collect();
+    }
+    converted.extend(unmapped); // values no mapping covers keep their number
+    converted
+}
@@ -0,0 +1,18 @@
+use aoc_common::{input_file_path, read_input_file, testing::assert_example, Part};
+use day_05::{parse_almanac, part2_by_ranges, Day05};
+
+#[test]
+fn part1_example() {
+    assert_example::<Day05>("input_example.txt", Part::One, 35);
+}
+
+#[test]
+fn part2_example() {
+    assert_example::<Day05>("input_example.txt", Part::Two, 46);
+}
+
+#[test]
+fn part2_by_ranges_example() {
+    let input_string = read_input_file(input_file_path(5, "input_example.txt")).unwrap();
+    assert_eq!(part2_by_ranges(&parse_almanac(&input_string).unwrap()), 46);
+}
@@ -0,0 +1,94 @@
+use aoc_common::{parse::{parse_numbers, strip_prefix}, AocError, Answer, ParseError, Solution};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    #[cfg(feature = "embedded-input")]
+    const EMBEDDED_INPUT: Option<&'static str> = Some(include_str!("input.txt"));
+
+    type Input<'a> = Vec<(usize, usize)>;
+
+    fn parse(input_string: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(parse_races(input_string)?)
+    }
+
+    fn part1(races: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part1(races).into())
+    }
+
+    fn part2(races: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part2(races).into())
+    }
+}
+
+pub fn parse_races(input_string: &str) -> Result<Vec<(usize, usize)>, ParseError> {
+    let mut lines = input_string.lines();
+    let time_line = lines.next().unwrap_or_default();
+    let race_durations: Vec<usize> = parse_numbers(input_string, strip_prefix(input_string, time_line, "Time:")?)?;
+    let distance_line = lines.next().ok_or_else(|| ParseError::at_end(input_string, "\"Distance:\""))?;
+    let record_distances: Vec<usize> = parse_numbers(input_string, strip_prefix(input_string, distance_line, "Distance:")?)?;
+    if record_distances.len() != race_durations.len() {
+        return Err(ParseError::at(input_string, distance_line, format!("{} record distances, one per race", race_durations.len())));
+    }
+    Ok(race_durations.into_iter().zip(record_distances).collect::<Vec<_>>())
+}
+
+pub fn part1(races: &[(usize,usize)]) -> usize {
+    races.iter().map(|&race| get_number_of_winning_strategies_for_race(race)).product()
+}
+
+fn get_number_of_winning_strategies_for_race(race: (usize,usize)) -> usize {
+    let (race_duration,record_distance) = race;
+    (1..race_duration) // for each charge time that is not 0 and not the entire race duration => boat will move
+        .map(|charge_time| {
+            let travel_time = race_duration - charge_time;
+            let travel_distance = travel_time * charge_time;
+            (travel_distance, record_distance)
+        })
+        .filter(|&(travel_distance, record_distance)| travel_distance > record_distance)
+        .count()
+}
+
+pub fn part2(races: &[(usize,usize)]) -> usize {
+    get_number_of_winning_strategies_for_race(concatenate_races(races))
+}
+
+fn concatenate_races(races: &[(usize,usize)]) -> (usize, usize) {
+    // no need to parse again, just concatenate digits
+    let race_duration = races.iter().map(|&(t,_)| t.to_string()).collect::<String>().parse::<usize>().unwrap();
+    let record_distance = races.iter().map(|&(_,d)| d.to_string()).collect::<String>().parse::<usize>().unwrap();
+    (race_duration, record_distance)
+}
+
+/// Same answer as `part1`, counting the winning charge times by bisection instead of trying each one.
+pub fn part1_by_bisection(races: &[(usize,usize)]) -> usize {
+    races.iter().map(|&race| count_winning_charge_times_by_bisection(race)).product()
+}
+
+/// Same answer as `part2`, counting the winning charge times by bisection instead of trying each one.
+pub fn part2_by_bisection(races: &[(usize,usize)]) -> usize {
+    count_winning_charge_times_by_bisection(concatenate_races(races))
+}
+
+/// The travel distance grows up to half the race duration and shrinks again symmetrically,
+/// so the winning charge times lie between the shortest one and its mirror image.
+fn count_winning_charge_times_by_bisection((race_duration, record_distance): (usize,usize)) -> usize {
+    // a distance too long for usize certainly beats the record
+    let wins = |charge_time: usize| charge_time.checked_mul(race_duration - charge_time).is_none_or(|travel_distance| travel_distance > record_distance);
+    let half_time = race_duration / 2;
+    if !wins(half_time) {
+        return 0;
+    }
+    let (mut low, mut high) = (0, half_time);
+    while low < high {
+        let charge_time = low + (high - low) / 2;
+        if wins(charge_time) {
+            high = charge_time;
+        } else {
+            low = charge_time + 1;
+        }
+    }
+    race_duration - 2 * low + 1
+}
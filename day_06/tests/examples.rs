@@ -0,0 +1,20 @@
+use aoc_common::{input_file_path, read_input_file, testing::assert_example, Part};
+use day_06::{parse_races, part1_by_bisection, part2_by_bisection, Day06};
+
+#[test]
+fn part1_example() {
+    assert_example::<Day06>("input_example.txt", Part::One, 288);
+}
+
+#[test]
+fn part2_example() {
+    assert_example::<Day06>("input_example.txt", Part::Two, 71503);
+}
+
+#[test]
+fn by_bisection_example() {
+    let input_string = read_input_file(input_file_path(6, "input_example.txt")).unwrap();
+    let races = parse_races(&input_string).unwrap();
+    assert_eq!(part1_by_bisection(&races), 288);
+    assert_eq!(part2_by_bisection(&races), 71503);
+}
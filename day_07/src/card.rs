@@ -1,23 +1,17 @@
-use std::cmp::Ordering;
+use std::{cmp::Ordering, hash::{Hash, Hasher}};
 
-#[derive(Debug, Clone, Eq, Hash)]
+#[derive(Debug, Clone, Eq)]
 pub struct Card {
     label: char,
+    strength: usize,
     joker_rule_enabled: bool
 }
 
 impl Card {
 
-    pub fn new(label: char) -> Self {
-        Self { label, joker_rule_enabled: false }
-    }
-
-    pub fn get_label(&self) -> char {
-        self.label
-    }
-
-    pub fn get_strength(&self) -> usize {
-        match self.label {
+    /// Returns None if the label is none of "23456789TJQKA".
+    pub fn new(label: char) -> Option<Self> {
+        let strength = match label {
             '2' => 1,
             '3' => 2,
             '4' => 3,
@@ -27,12 +21,24 @@ impl Card {
             '8' => 7,
             '9' => 8,
             'T' => 9,
-            'J' => if self.joker_rule_enabled { 0 } else { 10 },
+            'J' => 10,
             'Q' => 11,
             'K' => 12,
             'A' => 13,
-            _ => panic!()
+            _ => return None
+        };
+        Some(Self { label, strength, joker_rule_enabled: false })
+    }
+
+    pub fn get_label(&self) -> char {
+        self.label
+    }
+
+    pub fn get_strength(&self) -> usize {
+        if self.joker_rule_enabled && self.label == 'J' {
+            return 0;
         }
+        self.strength
     }
 
     pub fn enable_joker_rule(&mut self) {
@@ -42,7 +48,7 @@ impl Card {
 
 impl PartialOrd for Card {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(&other))
+        Some(self.cmp(other))
     }
 }
 
@@ -56,4 +62,11 @@ impl PartialEq for Card {
     fn eq(&self, other: &Self) -> bool {
         self.get_strength() == other.get_strength()
     }
+}
+
+impl Hash for Card {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // must agree with PartialEq which compares by strength
+        self.get_strength().hash(state);
+    }
 }
\ No newline at end of file
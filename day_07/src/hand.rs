@@ -1,4 +1,4 @@
-use std::{cmp::Ordering, str::Chars};
+use std::cmp::Ordering;
 
 use itertools::Itertools;
 
@@ -13,7 +13,7 @@ pub struct Hand {
 
 impl PartialOrd for Hand {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(&other))
+        Some(self.cmp(other))
     }
 }
 
@@ -33,9 +33,9 @@ impl Ord for Hand {
 
 impl Hand {
 
-    pub fn new(labels: Chars, bid: usize) -> Self {
+    pub fn new(cards: Vec<Card>, bid: usize) -> Self {
         Self {
-            cards: labels.map(|label| Card::new(label)).collect(),
+            cards,
             bid,
             joker_rule_enabled: false
         }
@@ -101,12 +101,12 @@ impl Hand {
     }
 
     fn get_max_type(&self) -> usize {
-        // replace any group of cards with joker cards and determine the max achievable type for this hand
-        self.get_grouped_card_labels().iter()
-            .map(|(card_label, _group_size)| self.cards.iter().map(|card| card.get_label()).collect::<String>().replace(&card_label.to_string(), "J"))
-            .map(|joker_hand_label| Hand::new(joker_hand_label.chars(), self.bid))
+        // let the jokers join any group of cards and determine the max achievable type for this hand
+        self.cards.iter()
+            .map(|group_card| self.cards.iter().map(|card| if card.get_label() == 'J' { group_card.clone() } else { card.clone() }).collect())
+            .map(|joker_hand_cards| Hand::new(joker_hand_cards, self.bid))
             .map(|joker_hand| joker_hand.get_type())
             .max()
-            .unwrap()
+            .unwrap_or_else(|| self.get_type())
     }
 }
\ No newline at end of file
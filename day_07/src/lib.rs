@@ -0,0 +1,63 @@
+pub mod card;
+pub mod hand;
+
+use aoc_common::{parse::{parse_lines, parse_number, split_once}, AocError, Answer, ParseError, Solution};
+use card::Card;
+use hand::Hand;
+use itertools::Itertools;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    #[cfg(feature = "embedded-input")]
+    const EMBEDDED_INPUT: Option<&'static str> = Some(include_str!("input.txt"));
+
+    type Input<'a> = Vec<Hand>;
+
+    fn parse(input_string: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(parse_hands(input_string)?)
+    }
+
+    fn part1(hands: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part1(hands).into())
+    }
+
+    fn part2(hands: &Self::Input<'_>) -> Result<Answer, AocError> {
+        // the joker rule is toggled on the hands themselves, so work on a copy
+        Ok(part2(&mut hands.clone()).into())
+    }
+}
+
+pub fn parse_hands(input_string: &str) -> Result<Vec<Hand>, ParseError> {
+    parse_lines(input_string, parse_hand)
+}
+
+fn parse_hand(line: &str) -> Result<Hand, ParseError> {
+    let (labels, bid) = split_once(line, line, " ")?;
+    let cards = labels.char_indices()
+        .map(|(i, label)| Card::new(label).ok_or_else(|| ParseError::at(line, &labels[i..i + label.len_utf8()], "card label")))
+        .collect::<Result<Vec<_>, _>>()?;
+    if cards.len() != 5 {
+        return Err(ParseError::at(line, labels, "hand of 5 cards"));
+    }
+    Ok(Hand::new(cards, parse_number(line, bid)?))
+}
+
+pub fn part1(hands: &[Hand]) -> usize {
+    hands.iter()
+        .sorted() // sorted by strength see impl of Hand and Card structs
+        .enumerate()
+        .map(|(rank, hand)| hand.get_bid() * (rank+1))
+        .sum::<usize>()
+}
+
+pub fn part2(hands: &mut [Hand]) -> usize {
+    hands.iter_mut().for_each(|hand| hand.enable_joker_rule()); // toggle joker mode
+    hands.iter()
+        .sorted() // sorted by strength see impl of Hand and Card structs
+        .enumerate()
+        .map(|(rank, hand)| hand.get_bid() * (rank+1))
+        .sum::<usize>()
+}
@@ -0,0 +1,12 @@
+use aoc_common::{testing::assert_example, Part};
+use day_07::Day07;
+
+#[test]
+fn part1_example() {
+    assert_example::<Day07>("input_example.txt", Part::One, 6440);
+}
+
+#[test]
+fn part2_example() {
+    assert_example::<Day07>("input_example.txt", Part::Two, 5905);
+}
@@ -0,0 +1,104 @@
+use std::collections::HashMap;
+
+use aoc_common::{parse::{split_once, strip_prefix}, AocError, Answer, ParseError, Solution};
+use num::Integer;
+use tracing::debug;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    #[cfg(feature = "embedded-input")]
+    const EMBEDDED_INPUT: Option<&'static str> = Some(include_str!("input.txt"));
+
+    type Input<'a> = (HashMap<&'a str, Vec<&'a str>>, Vec<usize>);
+
+    fn parse(input_string: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok((parse_network(input_string)?, parse_instructions(input_string)?))
+    }
+
+    fn part1((network, instructions): &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part1(network, instructions).into())
+    }
+
+    fn part2((network, instructions): &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part2(network, instructions).into())
+    }
+}
+
+pub fn parse_network(input_string: &str) -> Result<HashMap<&str, Vec<&str>>, ParseError> {
+    let mut lines = input_string.lines().skip(1); // skip the instructions
+    if let Some(separator_line) = lines.next().filter(|line| !line.is_empty()) {
+        return Err(ParseError::at(input_string, separator_line, "empty line after the instructions"));
+    }
+    let nodes = lines
+        .map(|line| {
+            let (node, next_nodes) = split_once(input_string, line, " = ")?;
+            let next_nodes = strip_prefix(input_string, next_nodes, "(")?;
+            let (left, right) = split_once(input_string, next_nodes, ", ")?;
+            let right = right.strip_suffix(')').ok_or_else(|| ParseError::at(input_string, &right[right.len()..], "\")\""))?;
+            for name in [node, left, right] {
+                check_node_name(input_string, name)?;
+            }
+            Ok((node, vec![left, right]))
+        })
+        .collect::<Result<Vec<_>, ParseError>>()?;
+    let network = nodes.iter().cloned().collect::<HashMap<_,_>>();
+    // every node that can be walked to has to be defined, otherwise walking the network would fail
+    for next_node in nodes.iter().flat_map(|(_, next_nodes)| next_nodes) {
+        if !network.contains_key(next_node) {
+            return Err(ParseError::at(input_string, next_node, "name of a node defined in the network"));
+        }
+    }
+    Ok(network)
+}
+
+fn check_node_name(input_string: &str, name: &str) -> Result<(), ParseError> {
+    if name.len() == 3 && name.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit()) {
+        Ok(())
+    } else {
+        Err(ParseError::at(input_string, name, "node name of three letters or digits"))
+    }
+}
+
+pub fn parse_instructions(input_string: &str) -> Result<Vec<usize>, ParseError> {
+    let line = input_string.lines().next().unwrap_or_default(); // first line
+    if line.is_empty() {
+        return Err(ParseError::at(input_string, line, "instructions"));
+    }
+    line.char_indices()
+        .map(|(i, c)| match c {
+            'L' => Ok(0),
+            'R' => Ok(1),
+            _ => Err(ParseError::at(input_string, &line[i..i + c.len_utf8()], "instruction \"L\" or \"R\"")),
+        })
+        .collect()
+}
+
+pub fn part1(network: &HashMap<&str, Vec<&str>>, instructions: &[usize]) -> usize {
+    let mut node = "AAA";
+    for (step, &instruction) in instructions.iter().cycle().enumerate() {
+        node = network[node][instruction];
+        if node == "ZZZ" {
+            return step + 1;
+        }
+    }
+    panic!("Should never reach this code...")
+}
+
+pub fn part2(network: &HashMap<&str, Vec<&str>>, instructions: &[usize]) -> usize {
+    let start_nodes = network.keys().filter(|node| node.ends_with("A")).cloned().collect::<Vec<_>>();
+    let steps_by_start_node = start_nodes.iter().map(|start_node| {
+        let mut node = start_node;
+        for (step, &instruction) in instructions.iter().cycle().enumerate() {
+            node = &network[node][instruction];
+            if node.ends_with("Z") {
+                debug!(start_node, end_node = node, steps = step + 1, "ghost arrived");
+                return step + 1;
+            }
+        }
+        panic!("Should never reach this code...")
+    }).collect::<Vec<_>>();
+    steps_by_start_node.iter().fold(1, |a,b| a.lcm(b)) // #total steps = largest common multiplier of all #steps for each start node
+}
@@ -0,0 +1,12 @@
+use aoc_common::{testing::assert_example, Part};
+use day_08::Day08;
+
+#[test]
+fn part1_example() {
+    assert_example::<Day08>("input_example1.txt", Part::One, 2);
+}
+
+#[test]
+fn part2_example() {
+    assert_example::<Day08>("input_example2.txt", Part::Two, 6);
+}
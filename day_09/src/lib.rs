@@ -0,0 +1,69 @@
+use aoc_common::{parse::{parse_lines, parse_numbers}, AocError, Answer, ParseError, Solution};
+use itertools::Itertools;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    #[cfg(feature = "embedded-input")]
+    const EMBEDDED_INPUT: Option<&'static str> = Some(include_str!("input.txt"));
+
+    type Input<'a> = Vec<Vec<i32>>;
+
+    fn parse(input_string: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(parse_histories(input_string)?)
+    }
+
+    fn part1(histories: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part1(histories).into())
+    }
+
+    fn part2(histories: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part2(histories).into())
+    }
+}
+
+pub fn parse_histories(input_string: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+    parse_lines(input_string, |line| {
+        let history = parse_numbers::<i32>(line, line)?;
+        if history.is_empty() {
+            return Err(ParseError::at(line, line, "history of at least one value"));
+        }
+        Ok(history)
+    })
+}
+
+fn get_diffsequences_for_histories(histories: &[Vec<i32>]) -> Vec<Vec<Vec<i32>>> {
+    histories.iter()
+        .map(|history| {
+            let mut current_diffseq = history.clone(); // add the history itself as first diffseq
+            let mut diffseqs_for_history = vec![current_diffseq.clone()];
+            while !current_diffseq.iter().all(|&b| b == 0) { // until the diffseq consists of zeroes
+                current_diffseq = current_diffseq.iter().tuple_windows().map(|(a,b)| b - a).collect_vec(); // pairwise diff
+                diffseqs_for_history.push(current_diffseq.clone());
+            }
+            diffseqs_for_history
+        })
+        .collect()
+}
+
+pub fn part1(histories: &[Vec<i32>]) -> i32 {
+    get_diffsequences_for_histories(histories).iter()
+        .map(|diffseqs| {
+            // sum up last entry of each sequence
+            diffseqs.iter()
+                .map(|seq| seq.iter().last().unwrap())
+                .sum::<i32>()
+        })
+        .sum()
+}
+
+pub fn part2(histories: &[Vec<i32>]) -> i32 {
+    get_diffsequences_for_histories(histories).iter()
+        .map(|diffseqs| {
+            // reversely iterate sequences and subtract extrapolated value from the first entry
+            diffseqs.iter().rev().fold(0, |a: i32, b: &Vec<i32>| b[0] - a)
+        })
+        .sum()
+}
@@ -0,0 +1,12 @@
+use aoc_common::{testing::assert_example, Part};
+use day_09::Day09;
+
+#[test]
+fn part1_example() {
+    assert_example::<Day09>("input_example.txt", Part::One, 114);
+}
+
+#[test]
+fn part2_example() {
+    assert_example::<Day09>("input_example.txt", Part::Two, 2);
+}
@@ -0,0 +1,6 @@
+use aoc_common::bench::bench_solution;
+use criterion::{criterion_group, criterion_main};
+use day_10::Day10;
+
+criterion_group!(benches, bench_solution::<Day10>);
+criterion_main!(benches);
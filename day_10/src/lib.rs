@@ -0,0 +1,126 @@
+pub mod tile;
+
+use std::collections::VecDeque;
+
+use aoc_common::{AocError, Answer, Grid, ParseError, Point, Solution};
+use itertools::Itertools;
+use tracing::debug;
+use tile::Tile;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    #[cfg(feature = "embedded-input")]
+    const EMBEDDED_INPUT: Option<&'static str> = Some(include_str!("input.txt"));
+
+    type Input<'a> = Grid<Tile>;
+
+    fn parse(input_string: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(parse_tile_grid(input_string)?)
+    }
+
+    fn part1(grid: &Self::Input<'_>) -> Result<Answer, AocError> {
+        // the start tile is reshaped while searching the loop, so work on a copy
+        Ok(part1(&mut grid.clone()).into())
+    }
+
+    fn part2(grid: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part2(&mut grid.clone()).into())
+    }
+}
+
+pub fn parse_tile_grid(input_string: &str) -> Result<Grid<Tile>, ParseError> {
+    let pipes = Grid::parse(input_string, |c| "|-LJ7F.S".contains(c).then_some(c), "pipe, ground or start position")?;
+    let grid = pipes.map(|position, &pipe| build_tile(pipe, Point::from_row_column(position)));
+    // the loop is searched from the start position, so there has to be exactly one
+    let mut start_positions = input_string.match_indices('S').map(|(i, _)| &input_string[i..i + 1]);
+    match (start_positions.next(), start_positions.next()) {
+        (None, _) => Err(ParseError::at_end(input_string, "start position \"S\"")),
+        (Some(_), Some(second_start)) => Err(ParseError::at(input_string, second_start, "a single start position")),
+        (Some(_), None) => Ok(grid),
+    }
+}
+
+fn build_tile(pipe: char, position: Point) -> Tile {
+    Tile {
+        position,
+        pipe,
+        is_start_position: pipe == 'S'
+    }
+}
+
+pub fn part1(grid: &mut Grid<Tile>) -> usize {
+    // S could be any pipe, so we need to test all possibilities
+    // find_loop returns empty vector when no loop could be found. Should be ok since we are interested in the largest vector?
+    // Possible edge case: two or more different loops depending on start shape? Not relevant for given input...
+    "|-F7JL".chars()
+        .map(|start_shape| find_loop(grid, start_shape))
+        .max_by_key(|loop_positions| loop_positions.len())
+        .map(|loop_positions| loop_positions.len()/2) // farthest point is half way through the loop
+        .unwrap()
+}
+
+fn find_loop(grid: &mut Grid<Tile>, start_shape: char) -> Vec<Point> {
+    let mut queue = VecDeque::new();
+    let mut visited_positions = vec![];
+    
+    let start_position = grid.find_all(|tile| tile.is_start_position).map(Point::from_row_column).next().unwrap();
+    let previous_shape = grid[start_position].pipe;
+    grid[start_position].pipe = start_shape;
+    queue.push_back(start_position);
+
+    while !queue.is_empty() {
+        let position = queue.pop_front().unwrap();
+        let tile = &grid[position];
+        visited_positions.push(tile.position);
+        
+        let connected_tiles = position.neighbors4()
+            .into_iter()
+            .filter_map(|neighbor| grid.get_point(neighbor))
+            .filter(|other_tile| tile.is_connected(other_tile))
+            .collect_vec();
+        if connected_tiles.len() != 2 {
+            // every pipe in the loop should be connected to exactly two other pipes
+            visited_positions.clear();
+            break;
+        }
+        for connected_tile in connected_tiles {
+            if !visited_positions.contains(&connected_tile.position) {
+                queue.push_back(connected_tile.position);
+            }
+        }
+    }
+    if visited_positions.is_empty() {
+        // keep the shape a loop was found with, part 2 needs the real one to count crossings
+        grid[start_position].pipe = previous_shape;
+    }
+    debug!(%start_shape, loop_length = visited_positions.len(), "loop searched");
+    visited_positions
+}
+
+pub fn part2(grid: &mut Grid<Tile>) -> usize {
+    "|-F7JL".chars()
+        .map(|start_shape| find_loop(grid, start_shape))
+        .max_by_key(|loop_positions| loop_positions.len())
+        .map(|loop_positions| {
+            // for each tile that is not part of the loop...
+            grid.positions()
+                .map(Point::from_row_column)
+                .filter(|p| !loop_positions.contains(p))
+                .map(|p| {
+                    // count how many crossings (|,J,L) are to the left of it ...
+                    (0..p.x)
+                        .map(|x| &grid[Point::new(x, p.y)])
+                        // TODO: speed up by preparing a grid that only contains the loop...
+                        .filter(|t| loop_positions.contains(&t.position))
+                        .filter(|t| ['|','J','L'].contains(&t.pipe))
+                        .count()
+                })
+                // when crossings are an odd number, the tile must be inside the loop
+                .filter(|&crossings| crossings % 2 == 1)
+                .count()
+        })
+        .unwrap()
+}
@@ -0,0 +1,20 @@
+use aoc_common::{solve, testing::assert_example, Answer, Part};
+use day_10::Day10;
+
+#[test]
+fn part1_example() {
+    assert_example::<Day10>("input_example.txt", Part::One, 8);
+}
+
+#[test]
+fn part2_example() {
+    assert_example::<Day10>("input_example2.txt", Part::Two, 10);
+}
+
+#[test]
+fn part2_with_start_on_an_f_tile() {
+    // the start has to count as the F it stands for, not as the last shape tried for it
+    let input = ".....\n.S-7.\n.|.|.\n.L-J.\n.....\n";
+    assert_eq!(solve::<Day10>(input, Part::One).unwrap(), Answer::from(4));
+    assert_eq!(solve::<Day10>(input, Part::Two).unwrap(), Answer::from(1));
+}
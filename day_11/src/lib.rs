@@ -0,0 +1,129 @@
+use std::collections::HashMap;
+
+use aoc_common::{AocError, Answer, Grid, ParseError, Point, Solution};
+use itertools::Itertools;
+use petgraph::{Graph, Directed, algo::floyd_warshall};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    #[cfg(feature = "embedded-input")]
+    const EMBEDDED_INPUT: Option<&'static str> = Some(include_str!("input.txt"));
+
+    type Input<'a> = Grid<char>;
+
+    fn parse(input_string: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(parse(input_string)?)
+    }
+
+    fn part1(image: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part1(image).into())
+    }
+
+    fn part2(image: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(part2(image).into())
+    }
+}
+
+pub fn parse(input_string: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(input_string, |c| (c == '.' || c == '#').then_some(c), "\".\" or \"#\"")
+}
+
+pub fn part1(image: &Grid<char>) -> u64 {
+    sum_of_distances_between_galaxies(image, 2)
+}
+
+pub fn part2(image: &Grid<char>) -> u64 {
+    sum_of_distances_between_galaxies(image, 1000000)
+}
+
+pub fn sum_of_distances_between_galaxies(image: &Grid<char>, expansion_factor: i64) -> u64 {
+    let galaxy_positions = find_galaxies(image);
+    let expansion = get_cosmic_expansion(image);
+    let expanded_galaxy_positions = expand_galaxy_positions(&galaxy_positions, expansion, expansion_factor);
+    let paths = get_paths_between_galaxies(&expanded_galaxy_positions);
+    paths.iter()
+        .map(|v| v[0].manhattan_distance(v[1]))
+        .sum()
+}
+
+/// Same answer as `sum_of_distances_between_galaxies`, without visiting every pair of galaxies.
+/// Manhattan distances add up per axis, and along one sorted axis the i-th of n coordinates is
+/// the larger one in i pairs and the smaller one in the remaining n - 1 - i.
+pub fn sum_of_distances_by_axis(image: &Grid<char>, expansion_factor: i64) -> u64 {
+    let galaxy_positions = find_galaxies(image);
+    let expansion = get_cosmic_expansion(image);
+    let expanded_galaxy_positions = expand_galaxy_positions(&galaxy_positions, expansion, expansion_factor);
+    let xs = expanded_galaxy_positions.iter().map(|galaxy| galaxy.x).sorted().collect_vec();
+    let ys = expanded_galaxy_positions.iter().map(|galaxy| galaxy.y).sorted().collect_vec();
+    [xs, ys].iter()
+        .map(|coordinates| {
+            let n = coordinates.len() as i64;
+            coordinates.iter().enumerate().map(|(i, &c)| c * (2 * i as i64 - (n - 1))).sum::<i64>() as u64
+        })
+        .sum()
+}
+
+fn find_galaxies(image: &Grid<char>) -> Vec<Point>{
+    image.find_all(|&pixel| pixel == '#').map(Point::from_row_column).collect_vec()
+}
+
+/// Empty rows (as y) and empty columns (as x) of the image.
+fn get_cosmic_expansion(image: &Grid<char>) -> (Vec<i64>,Vec<i64>) {
+    let expanding_y = image.rows()
+        .enumerate()
+        .filter(|&(_,row)| row.iter().all(|&c| c == '.')) // empty rows
+        .map(|(y,_)| y as i64)
+        .collect_vec();
+    let expanding_x = image.columns()
+        .enumerate()
+        .filter_map(|(x,mut column)| column.all(|&c| c == '.').then_some(x as i64)) // empty columns
+        .collect_vec();
+    (expanding_y, expanding_x)
+}
+
+fn expand_galaxy_positions(galaxy_positions: &[Point], expansion: (Vec<i64>,Vec<i64>), expansion_factor: i64) -> Vec<Point> {
+    let (expanding_y, expanding_x) = expansion;
+    galaxy_positions.iter()
+        .map(|galaxy| {
+            let expansion_x = expanding_x.iter().filter(|&&x| x < galaxy.x).count() as i64 * (expansion_factor-1);
+            let expansion_y = expanding_y.iter().filter(|&&y| y < galaxy.y).count() as i64 * (expansion_factor-1);
+            *galaxy + Point::new(expansion_x, expansion_y)
+        })
+        .collect_vec()
+}
+
+fn get_paths_between_galaxies(galaxies: &[Point]) -> Vec<Vec<Point>> {
+    galaxies.iter()
+        .copied()
+        .permutations(2)
+        .map(|permutation| permutation.iter().copied().sorted().collect_vec())
+        .unique() // after having sorted permutation vectors, we can eliminate duplicates like [(1,2),(3,4)] and [(3,4),(1,2)]
+        .collect_vec()
+}
+
+// so useless... but its already written so lets keep it around for next puzzles
+fn _solve_with_floyd_warshall(nodes: &[Point], edges: &[Vec<Point>]) -> i64 {
+    let mut graph: Graph<(), i64, Directed> = Graph::new();
+    let mut node_index_map = HashMap::new();
+    for node in nodes {
+        let index = graph.add_node(());
+        node_index_map.insert(node, index);
+    }
+    for edge in edges {
+        let start_node = node_index_map[&edge[0]];
+        let end_node = node_index_map[&edge[1]];
+        let weight = edge[0].manhattan_distance(edge[1]) as i64;
+        graph.add_edge(start_node, end_node, weight);
+    }
+    let distance_map = floyd_warshall(&graph, |edge| *edge.weight()).unwrap();
+    let distances = edges.iter().map(|edge| {
+        let a = node_index_map[&edge[0]];
+        let b = node_index_map[&edge[1]];
+        distance_map[&(a,b)]
+    })
+    .collect_vec();
+    distances.iter().sum()
+}
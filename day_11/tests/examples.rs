@@ -0,0 +1,25 @@
+use aoc_common::{input_file_path, read_input_file, testing::assert_example, Part};
+use day_11::{parse, sum_of_distances_between_galaxies, sum_of_distances_by_axis, Day11};
+
+#[test]
+fn part1_example() {
+    assert_example::<Day11>("input_example.txt", Part::One, 374);
+}
+
+#[test]
+fn part2_example_with_smaller_expansion_factors() {
+    // the puzzle text only gives answers for expansion factors 10 and 100
+    let input_string = read_input_file(input_file_path(11, "input_example.txt")).unwrap();
+    let image = parse(&input_string).unwrap();
+    assert_eq!(sum_of_distances_between_galaxies(&image, 10), 1030);
+    assert_eq!(sum_of_distances_between_galaxies(&image, 100), 8410);
+}
+
+#[test]
+fn sum_of_distances_by_axis_example() {
+    let input_string = read_input_file(input_file_path(11, "input_example.txt")).unwrap();
+    let image = parse(&input_string).unwrap();
+    for (expansion_factor, expected) in [(2, 374), (10, 1030), (100, 8410)] {
+        assert_eq!(sum_of_distances_by_axis(&image, expansion_factor), expected);
+    }
+}
@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(input_string) = std::str::from_utf8(data) else { return };
+    let _ = day_07::parse_hands(input_string);
+});
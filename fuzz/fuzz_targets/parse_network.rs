@@ -0,0 +1,10 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(input_string) = std::str::from_utf8(data) else { return };
+    // day 8 reads its input with both, so both have to cope with anything
+    let _ = day_08::parse_instructions(input_string);
+    let _ = day_08::parse_network(input_string);
+});